@@ -8,6 +8,7 @@ use std::{
     collections::HashMap,
     ffi::{OsStr, OsString},
     path::PathBuf,
+    process::Stdio,
 };
 
 use crate::{config::LintDependencyEntry, ExitStatus};
@@ -85,6 +86,37 @@ pub fn prepare_check(config: &Config) -> Result<CheckInfo, ExitStatus> {
     Ok(CheckInfo { env })
 }
 
+/// Prints the name, version and lints of every lint crate, that will be loaded
+/// by the driver. The lint crates have to be compiled, see [`prepare_check`].
+///
+/// This is purely informative. Errors are reported by the driver, but don't
+/// prevent the following check.
+pub fn print_lint_crates(config: &Config, info: &CheckInfo) {
+    // The driver has to be invoked via cargo, to ensure that the libraries
+    // are correctly linked. Errors of the driver are forwarded to stderr.
+    let output = config
+        .toolchain
+        .cargo_with_driver()
+        .arg("rustc")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(config.marker_dir.join("Cargo.toml").as_os_str())
+        .arg("--")
+        .arg("--lint-crates")
+        .envs(info.env.iter().map(|(key, value)| (key, value)))
+        .stderr(Stdio::inherit())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => {
+            println!();
+            println!("Lint crates:");
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+        },
+        _ => eprintln!("Warning: The lint crates couldn't be listed, linting continues regardless"),
+    }
+}
+
 pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[String]) -> Result<(), ExitStatus> {
     println!();
     println!("Start linting:");
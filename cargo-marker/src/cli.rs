@@ -64,7 +64,7 @@ pub fn get_clap_config() -> Command {
                 .short('v')
                 .long("verbose")
                 .action(ArgAction::SetTrue)
-                .help("Print additional debug information to the console"),
+                .help("Print additional debug information, like the loaded lint crates, to the console"),
         )
         .arg(
             Arg::new("test-setup")
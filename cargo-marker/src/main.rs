@@ -108,6 +108,9 @@ fn run_check(args: &clap::ArgMatches, config: Option<Config>, flags: &Flags) ->
             .skip_while(|c| c != CARGO_ARGS_SEPARATOR)
             .skip(1)
             .collect();
+        if flags.verbose {
+            backend::print_lint_crates(&backend_conf, &info);
+        }
         backend::run_check(&backend_conf, info, &additional_cargo_args)
     }
 }
@@ -5,9 +5,15 @@
 
 use marker_api::{
     ast::{
-        item::{Body, ItemKind},
+        attr::Attribute,
+        expr::{ExprKind, MethodTarget},
+        generic::SemGenericArgKind,
+        item::{Body, ItemKind, SemItem},
+        pat::IdentPat,
+        stmt::StmtKind,
         ty::SemTyKind,
-        BodyId, ExprId, ItemId, Span, SpanId, SymbolId, TyDefId,
+        BodyId, ConstValue, DefPath, DefPathId, ExpnInfo, ExprId, FileLocation, ItemId, NodeId, NodeKind, Span, SpanId,
+        SpanSrcId, StmtId, SymbolId, TyDefId, VarId, VariantId,
     },
     context::DriverCallbacks,
     diagnostic::{Diagnostic, EmissionNode},
@@ -43,12 +49,31 @@ impl<'ast> DriverContextWrapper<'ast> {
             driver_context: unsafe { &*(self as *const DriverContextWrapper).cast::<()>() },
             lint_level_at,
             emit_diag,
+            report_lint_crate_panic,
             item,
             body,
+            eval_const,
+            expr,
+            stmt,
+            var,
+            sem_item,
+            ancestors,
             resolve_ty_ids,
+            resolve_fn_ids,
+            resolve_const_ids,
+            resolve_static_ids,
+            resolve_trait_ids,
+            resolve_variant_ids,
+            def_path,
+            implements_trait,
             expr_ty,
+            var_ty,
+            expr_attrs,
+            is_exported,
             span,
             span_snippet,
+            span_file_location,
+            span_expn_info,
             symbol_str,
             resolve_method_target,
         }
@@ -66,6 +91,18 @@ extern "C" fn emit_diag<'a, 'ast>(data: &(), diag: &Diagnostic<'a, 'ast>) {
     wrapper.driver_cx.emit_diag(diag);
 }
 
+extern "C" fn report_lint_crate_panic<'a, 'ast>(
+    data: &(),
+    krate: ffi::FfiStr<'a>,
+    node: ffi::FfiStr<'a>,
+    span: ffi::FfiOption<&'a Span<'ast>>,
+) {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper
+        .driver_cx
+        .report_lint_crate_panic(krate.get(), node.get(), span.copy());
+}
+
 #[allow(improper_ctypes_definitions, reason = "fp because `ItemKind` is non-exhaustive")]
 extern "C" fn item<'ast>(data: &(), id: ItemId) -> FfiOption<ItemKind<'ast>> {
     let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
@@ -77,17 +114,109 @@ extern "C" fn body<'ast>(data: &(), id: BodyId) -> &'ast Body<'ast> {
     wrapper.driver_cx.body(id)
 }
 
+extern "C" fn eval_const<'ast>(data: &(), id: BodyId) -> &'ast ConstValue<'ast> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.eval_const(id)
+}
+
+#[allow(improper_ctypes_definitions, reason = "fp because `ExprKind` is non-exhaustive")]
+extern "C" fn expr<'ast>(data: &(), id: ExprId) -> FfiOption<ExprKind<'ast>> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.expr(id).into()
+}
+
+#[allow(improper_ctypes_definitions, reason = "fp because `StmtKind` is non-exhaustive")]
+extern "C" fn stmt<'ast>(data: &(), id: StmtId) -> FfiOption<StmtKind<'ast>> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.stmt(id).into()
+}
+
+extern "C" fn var<'ast>(data: &(), id: VarId) -> FfiOption<&'ast IdentPat<'ast>> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.var(id).into()
+}
+
+#[allow(improper_ctypes_definitions, reason = "fp because `DefPathId` is non-exhaustive")]
+extern "C" fn sem_item<'ast>(data: &(), id: DefPathId) -> FfiOption<&'ast SemItem<'ast>> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.sem_item(id).into()
+}
+
+#[allow(improper_ctypes_definitions, reason = "fp because `NodeId` is non-exhaustive")]
+extern "C" fn ancestors<'ast>(data: &(), id: NodeId) -> ffi::FfiSlice<'ast, NodeKind<'ast>> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.ancestors(id).into()
+}
+
 extern "C" fn resolve_ty_ids<'ast>(data: &(), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId> {
     let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
     wrapper.driver_cx.resolve_ty_ids((&path).into()).into()
 }
 
+extern "C" fn resolve_fn_ids<'ast>(data: &(), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.resolve_fn_ids((&path).into()).into()
+}
+
+extern "C" fn resolve_const_ids<'ast>(data: &(), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.resolve_const_ids((&path).into()).into()
+}
+
+extern "C" fn resolve_static_ids<'ast>(data: &(), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.resolve_static_ids((&path).into()).into()
+}
+
+extern "C" fn resolve_trait_ids<'ast>(data: &(), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.resolve_trait_ids((&path).into()).into()
+}
+
+extern "C" fn resolve_variant_ids<'ast>(data: &(), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, VariantId> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.resolve_variant_ids((&path).into()).into()
+}
+
+#[allow(improper_ctypes_definitions, reason = "fp because `DefPathId` is non-exhaustive")]
+extern "C" fn def_path<'ast>(data: &(), id: DefPathId) -> &'ast DefPath<'ast> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.def_path(id)
+}
+
+#[allow(improper_ctypes_definitions, reason = "fp because `TyKind` is non-exhaustive")]
+extern "C" fn implements_trait<'ast>(
+    data: &(),
+    ty: SemTyKind<'ast>,
+    trait_id: TyDefId,
+    generic_args: ffi::FfiSlice<'_, SemGenericArgKind<'ast>>,
+) -> bool {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.implements_trait(ty, trait_id, generic_args.get())
+}
+
 #[allow(improper_ctypes_definitions, reason = "fp because `TyKind` is non-exhaustive")]
 extern "C" fn expr_ty<'ast>(data: &(), expr: ExprId) -> SemTyKind<'ast> {
     let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
     wrapper.driver_cx.expr_ty(expr)
 }
 
+#[allow(improper_ctypes_definitions, reason = "fp because `SemTyKind` is non-exhaustive")]
+extern "C" fn var_ty<'ast>(data: &(), var: VarId) -> SemTyKind<'ast> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.var_ty(var)
+}
+
+extern "C" fn expr_attrs<'ast>(data: &(), expr: ExprId) -> ffi::FfiSlice<'ast, Attribute<'ast>> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.expr_attrs(expr).into()
+}
+
+extern "C" fn is_exported(data: &(), id: ItemId) -> bool {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.is_exported(id)
+}
+
 extern "C" fn span<'ast>(data: &(), span_id: SpanId) -> &'ast Span<'ast> {
     let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
     wrapper.driver_cx.span(span_id)
@@ -98,12 +227,22 @@ extern "C" fn span_snippet<'ast>(data: &(), span: &Span<'ast>) -> ffi::FfiOption
     wrapper.driver_cx.span_snippet(span).map(Into::into).into()
 }
 
+extern "C" fn span_file_location<'ast>(data: &(), span: &Span<'ast>) -> ffi::FfiOption<&'ast FileLocation<'ast>> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.span_file_location(span).into()
+}
+
+extern "C" fn span_expn_info<'ast>(data: &(), src_id: SpanSrcId) -> ffi::FfiOption<&'ast ExpnInfo<'ast>> {
+    let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
+    wrapper.driver_cx.span_expn_info(src_id).into()
+}
+
 extern "C" fn symbol_str<'ast>(data: &(), sym: SymbolId) -> ffi::FfiStr<'ast> {
     let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
     wrapper.driver_cx.symbol_str(sym).into()
 }
 
-extern "C" fn resolve_method_target(data: &(), id: ExprId) -> ItemId {
+extern "C" fn resolve_method_target<'ast>(data: &(), id: ExprId) -> &'ast MethodTarget<'ast> {
     let wrapper = unsafe { &*(data as *const ()).cast::<DriverContextWrapper>() };
     wrapper.driver_cx.resolve_method_target(id)
 }
@@ -111,15 +250,39 @@ extern "C" fn resolve_method_target(data: &(), id: ExprId) -> ItemId {
 pub trait DriverContext<'ast> {
     fn lint_level_at(&'ast self, lint: &'static Lint, node: EmissionNode) -> Level;
     fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>);
+    fn report_lint_crate_panic(&'ast self, krate: &str, node: &str, span: Option<&Span<'ast>>);
 
     fn item(&'ast self, api_id: ItemId) -> Option<ItemKind<'ast>>;
     fn body(&'ast self, api_id: BodyId) -> &'ast Body<'ast>;
+    fn eval_const(&'ast self, id: BodyId) -> &'ast ConstValue<'ast>;
+    fn expr(&'ast self, id: ExprId) -> Option<ExprKind<'ast>>;
+    fn stmt(&'ast self, id: StmtId) -> Option<StmtKind<'ast>>;
+    fn var(&'ast self, id: VarId) -> Option<&'ast IdentPat<'ast>>;
+    fn sem_item(&'ast self, id: DefPathId) -> Option<&'ast SemItem<'ast>>;
+    fn ancestors(&'ast self, id: NodeId) -> &'ast [NodeKind<'ast>];
 
     fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId];
+    fn resolve_fn_ids(&'ast self, path: &str) -> &'ast [ItemId];
+    fn resolve_const_ids(&'ast self, path: &str) -> &'ast [ItemId];
+    fn resolve_static_ids(&'ast self, path: &str) -> &'ast [ItemId];
+    fn resolve_trait_ids(&'ast self, path: &str) -> &'ast [TyDefId];
+    fn resolve_variant_ids(&'ast self, path: &str) -> &'ast [VariantId];
+    fn def_path(&'ast self, id: DefPathId) -> &'ast DefPath<'ast>;
+    fn implements_trait(
+        &'ast self,
+        ty: SemTyKind<'ast>,
+        trait_id: TyDefId,
+        generic_args: &[SemGenericArgKind<'ast>],
+    ) -> bool;
 
     fn expr_ty(&'ast self, expr: ExprId) -> SemTyKind<'ast>;
+    fn var_ty(&'ast self, var: VarId) -> SemTyKind<'ast>;
+    fn expr_attrs(&'ast self, expr: ExprId) -> &'ast [Attribute<'ast>];
+    fn is_exported(&'ast self, id: ItemId) -> bool;
     fn span(&'ast self, owner: SpanId) -> &'ast Span<'ast>;
     fn span_snippet(&'ast self, span: &Span<'ast>) -> Option<&'ast str>;
+    fn span_file_location(&'ast self, span: &Span<'ast>) -> Option<&'ast FileLocation<'ast>>;
+    fn span_expn_info(&'ast self, src_id: SpanSrcId) -> Option<&'ast ExpnInfo<'ast>>;
     fn symbol_str(&'ast self, api_id: SymbolId) -> &'ast str;
-    fn resolve_method_target(&'ast self, id: ExprId) -> ItemId;
+    fn resolve_method_target(&'ast self, id: ExprId) -> &'ast MethodTarget<'ast>;
 }
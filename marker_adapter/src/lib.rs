@@ -6,14 +6,16 @@
 
 pub mod context;
 mod loader;
-pub use loader::LintCrateInfo;
+pub use loader::{LintCrateInfo, LintCrateMetadata};
 use loader::{LintCrateRegistry, LoadingError};
 
 use marker_api::{
     ast::{
         expr::ExprKind,
         item::{Body, EnumVariant, Field, ItemKind},
+        pat::PatKind,
         stmt::StmtKind,
+        ty::SynTyKind,
         Crate,
     },
     context::AstContext,
@@ -33,7 +35,7 @@ pub enum AdapterError {
     /// the `marker_adapter` crate.
     #[error("the content of the `{LINT_CRATES_ENV}` environment value is malformed")]
     LintCratesEnvMalformed,
-    #[error("error while loading the lint crate: {0:#?}")]
+    #[error("error while loading the lint crate: {0}")]
     LoadingError(#[from] LoadingError),
 }
 
@@ -74,14 +76,22 @@ impl Adapter {
         self.inner.borrow().external_lint_crates.collect_lint_pass_info()
     }
 
-    pub fn process_krate<'ast>(&self, cx: &'ast AstContext<'ast>, krate: &Crate<'ast>) {
+    /// Returns the metadata of all loaded lint crates.
+    #[must_use]
+    pub fn lint_crate_metadata(&self) -> Vec<LintCrateMetadata> {
+        self.inner.borrow().external_lint_crates.collect_lint_crate_metadata()
+    }
+
+    pub fn process_krate<'ast>(&self, cx: &'ast AstContext<'ast>, krate: &'ast Crate<'ast>) {
         let inner = &mut *self.inner.borrow_mut();
 
         inner.external_lint_crates.set_ast_context(cx);
 
+        inner.external_lint_crates.check_crate(cx, krate);
         for item in krate.items() {
             visitor::traverse_item::<()>(cx, inner, *item);
         }
+        inner.external_lint_crates.check_crate_post(cx, krate);
     }
 }
 
@@ -91,6 +101,11 @@ impl Visitor<()> for AdapterInner {
         ControlFlow::Continue(())
     }
 
+    fn visit_item_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_item_post(cx, item);
+        ControlFlow::Continue(())
+    }
+
     fn visit_field<'ast>(&mut self, cx: &'ast AstContext<'ast>, field: &'ast Field<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_field(cx, field);
         ControlFlow::Continue(())
@@ -106,13 +121,38 @@ impl Visitor<()> for AdapterInner {
         ControlFlow::Continue(())
     }
 
+    fn visit_body_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_body_post(cx, body);
+        ControlFlow::Continue(())
+    }
+
     fn visit_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_stmt(cx, stmt);
         ControlFlow::Continue(())
     }
 
+    fn visit_stmt_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_stmt_post(cx, stmt);
+        ControlFlow::Continue(())
+    }
+
     fn visit_expr<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_expr(cx, expr);
         ControlFlow::Continue(())
     }
+
+    fn visit_expr_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_expr_post(cx, expr);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_pat<'ast>(&mut self, cx: &'ast AstContext<'ast>, pat: PatKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_pat(cx, pat);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ty<'ast>(&mut self, cx: &'ast AstContext<'ast>, ty: SynTyKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_ty(cx, ty);
+        ControlFlow::Continue(())
+    }
 }
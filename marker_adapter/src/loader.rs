@@ -1,7 +1,21 @@
 use libloading::Library;
-use marker_api::{interface::LintCrateBindings, AstContext};
-use marker_api::{LintPass, LintPassInfo, MARKER_API_VERSION};
-use std::path::PathBuf;
+use marker_api::{
+    ast::{
+        expr::ExprKind,
+        item::{Body, EnumVariant, Field, ItemKind},
+        pat::PatKind,
+        stmt::StmtKind,
+        ty::SynTyKind,
+        Crate, Span,
+    },
+    diagnostic::EmissionNode,
+    interface::LintCrateBindings,
+    lint::{Level, Lint},
+    AstContext,
+};
+use marker_api::{LintPass, LintPassInfo, MARKER_API_ABI_FINGERPRINT, MARKER_API_VERSION};
+use semver::{Comparator, Op, Version, VersionReq};
+use std::{collections::HashMap, path::PathBuf};
 use thiserror::Error;
 
 use super::{AdapterError, LINT_CRATES_ENV};
@@ -31,11 +45,36 @@ impl LintCrateInfo {
     }
 }
 
+/// Metadata of a loaded lint crate, provided by the lint crate's
+/// [`LintPassInfo`]. Drivers can use this to report which lint crates are
+/// active.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct LintCrateMetadata {
+    /// The name of the lint crate. This defaults to the file name of the
+    /// library, if the lint crate doesn't provide a name.
+    pub name: String,
+    /// The path of the loaded library.
+    pub path: PathBuf,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub homepage: Option<String>,
+    pub repository: Option<String>,
+    /// The features of `marker_api`, which are required by the lint crate.
+    pub required_api_features: Vec<String>,
+    /// The names of all lints, declared by the lint crate. The names are
+    /// lowercase, like they are used in attributes.
+    pub lints: Vec<String>,
+}
+
 /// This struct loads external lint crates into memory and provides a safe API
 /// to call the respective methods on all of them.
 #[derive(Debug, Default)]
 pub struct LintCrateRegistry {
     passes: Vec<LoadedLintCrate>,
+    /// The `active` states of the lint crates, from before entering the
+    /// current item. They are restored, when the item is left.
+    scopes: Vec<Vec<bool>>,
 }
 
 impl LintCrateRegistry {
@@ -45,16 +84,117 @@ impl LintCrateRegistry {
         for krate in lint_crates {
             new_self.passes.push(LoadedLintCrate::try_from_info(krate.clone())?);
         }
+        new_self.check_duplicate_lints()?;
 
         Ok(new_self)
     }
 
+    /// Rustc requires lint names to be unique. Lint names are namespaced by
+    /// their lint crate, duplicates can still occur, if two loaded lint crates
+    /// have the same name or if a lint crate registers a lint twice.
+    fn check_duplicate_lints(&self) -> Result<(), LoadingError> {
+        // Rustc compares lint names in lowercase
+        let mut lints: HashMap<String, String> = HashMap::new();
+        for krate in &self.passes {
+            for lint in krate.lints {
+                let name = lint.name.to_lowercase();
+                if let Some(first_krate) = lints.insert(name.clone(), krate.name()) {
+                    return Err(LoadingError::DuplicateLint {
+                        lint: name,
+                        first_krate,
+                        second_krate: krate.name(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(super) fn set_ast_context<'ast>(&self, cx: &'ast AstContext<'ast>) {
         for lint_pass in &self.passes {
             (lint_pass.bindings.set_ast_context)(cx);
         }
     }
 
+    /// Lint crates, which have all their lints allowed at the given node, are
+    /// skipped until the state is updated for a different node. Lint levels can
+    /// be changed by attributes on any item, the state is therefore updated for
+    /// the crate root and every item. Attributes on statements and expressions
+    /// can't reactivate skipped lint crates.
+    ///
+    /// The crate hooks are not affected by this, see [`Self::activate_all`].
+    fn update_active<'ast>(&mut self, cx: &'ast AstContext<'ast>, node: EmissionNode) {
+        for krate in &mut self.passes {
+            // Lint crates without lints can't be skipped, as their levels are unknown
+            krate.active = krate.lints.is_empty()
+                || krate
+                    .lints
+                    .iter()
+                    .any(|lint| cx.lint_level_at(lint, node) != Level::Allow);
+        }
+    }
+
+    /// The crate hooks `check_crate` and `check_crate_post` are called for all
+    /// lint crates, which haven't been disabled. This allows lint crates to
+    /// consistently initialize and finalize their state, even if they are
+    /// skipped for all items.
+    fn activate_all(&mut self) {
+        for krate in &mut self.passes {
+            krate.active = true;
+        }
+    }
+
+    fn enter_scope<'ast>(&mut self, cx: &'ast AstContext<'ast>, node: EmissionNode) {
+        self.scopes.push(self.passes.iter().map(|krate| krate.active).collect());
+        self.update_active(cx, node);
+    }
+
+    fn leave_scope(&mut self) {
+        let scope = self.scopes.pop().expect("every left scope has been entered");
+        for (krate, active) in self.passes.iter_mut().zip(scope) {
+            krate.active = active;
+        }
+    }
+
+    /// Calls the given `check` function for every lint crate, which hasn't been
+    /// disabled or skipped. Panics are caught inside the lint crate and reported via the
+    /// return value. They are then reported as warnings by the driver. Lint crates which
+    /// panicked are disabled for the rest of the session, to allow the other lint
+    /// crates to continue linting.
+    fn check_each<'ast>(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        node: &str,
+        span: Option<&Span<'ast>>,
+        check: impl Fn(&LintCrateBindings) -> bool,
+    ) {
+        for krate in self.passes.iter_mut().filter(|krate| krate.active && !krate.disabled) {
+            if !check(&krate.bindings) {
+                krate.disabled = true;
+                cx.report_lint_crate_panic(&krate.name(), node, span);
+            }
+        }
+    }
+
+    pub(crate) fn collect_lint_crate_metadata(&self) -> Vec<LintCrateMetadata> {
+        self.passes
+            .iter()
+            .map(|krate| {
+                let info = (krate.bindings.info)();
+                LintCrateMetadata {
+                    name: info.crate_name().map_or_else(|| krate.name(), ToString::to_string),
+                    path: krate.info.path.clone(),
+                    version: info.version().map(ToString::to_string),
+                    description: info.description().map(ToString::to_string),
+                    homepage: info.homepage().map(ToString::to_string),
+                    repository: info.repository().map(ToString::to_string),
+                    required_api_features: info.required_api_features().map(ToString::to_string).collect(),
+                    lints: info.lints().iter().map(|lint| lint.name.to_lowercase()).collect(),
+                }
+            })
+            .collect()
+    }
+
     pub(crate) fn collect_lint_pass_info(&self) -> Vec<LintPassInfo> {
         let mut info = vec![];
         for pass in &self.passes {
@@ -70,44 +210,73 @@ impl LintPass for LintCrateRegistry {
         panic!("`registered_lints` should not be called on `LintCrateRegistry`");
     }
 
-    fn check_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: marker_api::ast::item::ItemKind<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_item)(cx, item);
-        }
+    fn check_crate<'ast>(&mut self, cx: &'ast AstContext<'ast>, krate: &'ast Crate<'ast>) {
+        self.activate_all();
+        self.check_each(cx, "the crate", None, |lp| (lp.check_crate)(cx, krate));
+        self.update_active(cx, krate.id().into());
     }
 
-    fn check_field<'ast>(&mut self, cx: &'ast AstContext<'ast>, field: &'ast marker_api::ast::item::Field<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_field)(cx, field);
-        }
+    fn check_crate_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, krate: &'ast Crate<'ast>) {
+        self.activate_all();
+        self.check_each(cx, "the crate", None, |lp| (lp.check_crate_post)(cx, krate));
     }
 
-    fn check_variant<'ast>(
-        &mut self,
-        cx: &'ast AstContext<'ast>,
-        variant: &'ast marker_api::ast::item::EnumVariant<'ast>,
-    ) {
-        for lp in &self.passes {
-            (lp.bindings.check_variant)(cx, variant);
-        }
+    fn check_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+        self.enter_scope(cx, item.id().into());
+        self.check_each(cx, "an item", Some(item.span()), |lp| (lp.check_item)(cx, item));
     }
 
-    fn check_body<'ast>(&mut self, cx: &'ast AstContext<'ast>, body: &'ast marker_api::ast::item::Body<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_body)(cx, body);
-        }
+    fn check_item_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+        self.check_each(cx, "an item", Some(item.span()), |lp| (lp.check_item_post)(cx, item));
+        self.leave_scope();
     }
 
-    fn check_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: marker_api::ast::stmt::StmtKind<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_stmt)(cx, stmt);
-        }
+    fn check_field<'ast>(&mut self, cx: &'ast AstContext<'ast>, field: &'ast Field<'ast>) {
+        self.check_each(cx, "a field", Some(field.span()), |lp| (lp.check_field)(cx, field));
     }
 
-    fn check_expr<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: marker_api::ast::expr::ExprKind<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_expr)(cx, expr);
-        }
+    fn check_variant<'ast>(&mut self, cx: &'ast AstContext<'ast>, variant: &'ast EnumVariant<'ast>) {
+        self.check_each(cx, "an enum variant", Some(variant.span()), |lp| {
+            (lp.check_variant)(cx, variant)
+        });
+    }
+
+    fn check_body<'ast>(&mut self, cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>) {
+        self.check_each(cx, "a body", Some(body.expr().span()), |lp| (lp.check_body)(cx, body));
+    }
+
+    fn check_body_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>) {
+        self.check_each(cx, "a body", Some(body.expr().span()), |lp| {
+            (lp.check_body_post)(cx, body)
+        });
+    }
+
+    fn check_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) {
+        self.check_each(cx, "a statement", Some(stmt.span()), |lp| (lp.check_stmt)(cx, stmt));
+    }
+
+    fn check_stmt_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) {
+        self.check_each(cx, "a statement", Some(stmt.span()), |lp| {
+            (lp.check_stmt_post)(cx, stmt)
+        });
+    }
+
+    fn check_expr<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) {
+        self.check_each(cx, "an expression", Some(expr.span()), |lp| (lp.check_expr)(cx, expr));
+    }
+
+    fn check_expr_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) {
+        self.check_each(cx, "an expression", Some(expr.span()), |lp| {
+            (lp.check_expr_post)(cx, expr)
+        });
+    }
+
+    fn check_pat<'ast>(&mut self, cx: &'ast AstContext<'ast>, pat: PatKind<'ast>) {
+        self.check_each(cx, "a pattern", Some(pat.span()), |lp| (lp.check_pat)(cx, pat));
+    }
+
+    fn check_ty<'ast>(&mut self, cx: &'ast AstContext<'ast>, ty: SynTyKind<'ast>) {
+        self.check_each(cx, "a type", Some(ty.span()), |lp| (lp.check_ty)(cx, ty));
     }
 }
 
@@ -115,12 +284,22 @@ struct LoadedLintCrate {
     _lib: &'static Library,
     info: LintCrateInfo,
     bindings: LintCrateBindings,
+    /// The lints declared by this lint crate.
+    lints: &'static [&'static Lint],
+    /// Lint crates are inactive, while all their lints are allowed.
+    active: bool,
+    /// Lint crates are disabled, if they panicked during a `check_*` call.
+    disabled: bool,
 }
 
 #[allow(clippy::missing_fields_in_debug)]
 impl std::fmt::Debug for LoadedLintCrate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("LoadedLintCrate").field("info", &self.info).finish()
+        f.debug_struct("LoadedLintCrate")
+            .field("info", &self.info)
+            .field("active", &self.active)
+            .field("disabled", &self.disabled)
+            .finish()
     }
 }
 
@@ -142,8 +321,18 @@ impl LoadedLintCrate {
             }
         };
         let krate_api_version = unsafe { get_api_version() };
-        if krate_api_version != MARKER_API_VERSION {
-            return Err(LoadingError::IncompatibleVersion {
+        check_api_version(krate_api_version)?;
+
+        // The fingerprint ensures that the shared types have the same definitions,
+        // even if the versions are semver compatible.
+        let get_abi_fingerprint = unsafe {
+            lib.get::<unsafe extern "C" fn() -> u64>(b"marker_api_abi_fingerprint\0")
+                .map_err(|_| LoadingError::IncompatibleAbi {
+                    krate_version: krate_api_version.to_string(),
+                })?
+        };
+        if unsafe { get_abi_fingerprint() } != MARKER_API_ABI_FINGERPRINT {
+            return Err(LoadingError::IncompatibleAbi {
                 krate_version: krate_api_version.to_string(),
             });
         }
@@ -154,13 +343,64 @@ impl LoadedLintCrate {
                 .map_err(|_| LoadingError::MissingBindingSymbol)?
         };
         let bindings = get_lint_crate_bindings();
+        let lints = (bindings.info)().lints();
 
         Ok(Self {
             _lib: lib,
             info,
             bindings,
+            lints,
+            active: true,
+            disabled: false,
         })
     }
+
+    /// The name of the lint crate, derived from the file name of the library.
+    fn name(&self) -> String {
+        let stem = self.info.path.file_stem().unwrap_or_default().to_string_lossy();
+        let name = if cfg!(windows) {
+            &stem
+        } else {
+            stem.strip_prefix("lib").unwrap_or(&stem)
+        };
+        name.to_string()
+    }
+}
+
+/// Lint crates can be loaded, if the driver's `marker_api` version is semver
+/// compatible with the version used by the lint crate. This follows cargo's
+/// caret requirements, meaning that a lint crate compiled with `0.1.0` can be
+/// loaded by a driver using `0.1.1`, but not by one using `0.2.0`.
+fn check_api_version(krate_version: &str) -> Result<(), LoadingError> {
+    let driver_version = Version::parse(MARKER_API_VERSION).expect("the driver version is always valid");
+    let Ok(parsed_krate_version) = Version::parse(krate_version) else {
+        return Err(LoadingError::InvalidVersion {
+            krate_version: krate_version.to_string(),
+        });
+    };
+
+    let req = VersionReq {
+        comparators: vec![Comparator {
+            op: Op::Caret,
+            major: parsed_krate_version.major,
+            minor: Some(parsed_krate_version.minor),
+            patch: Some(parsed_krate_version.patch),
+            pre: parsed_krate_version.pre.clone(),
+        }],
+    };
+    if req.matches(&driver_version) {
+        return Ok(());
+    }
+
+    let help = if parsed_krate_version > driver_version {
+        "update the marker driver and `cargo-marker` to a compatible version".to_string()
+    } else {
+        format!("update the lint crate to use `marker_api` version `{MARKER_API_VERSION}` and recompile it")
+    };
+    Err(LoadingError::IncompatibleVersion {
+        krate_version: krate_version.to_string(),
+        help,
+    })
 }
 
 #[derive(Error, Debug)]
@@ -171,6 +411,23 @@ pub enum LoadingError {
     MissingApiSymbol,
     #[error("the loaded crate doesn't contain the `marker_lint_crate_bindings` symbol")]
     MissingBindingSymbol,
-    #[error("incompatible api version:\n- lint-crate api: {krate_version}\n- driver api: {MARKER_API_VERSION}")]
-    IncompatibleVersion { krate_version: String },
+    #[error("the lint crate uses an invalid `marker_api` version: `{krate_version}`")]
+    InvalidVersion { krate_version: String },
+    #[error(
+        "incompatible api version:\n- lint-crate api: {krate_version}\n- driver api: {MARKER_API_VERSION}\n\
+        help: {help}"
+    )]
+    IncompatibleVersion { krate_version: String, help: String },
+    #[error(
+        "incompatible api definitions, despite compatible versions:\n- lint-crate api: {krate_version}\n\
+        - driver api: {MARKER_API_VERSION}\n\
+        help: recompile the lint crate with `marker_api` version `{MARKER_API_VERSION}`"
+    )]
+    IncompatibleAbi { krate_version: String },
+    #[error("the lint `{lint}` is declared by the lint crates `{first_krate}` and `{second_krate}`")]
+    DuplicateLint {
+        lint: String,
+        first_krate: String,
+        second_krate: String,
+    },
 }
@@ -3,8 +3,9 @@ pub use common::*;
 
 use crate::ffi::FfiSlice;
 
-use self::item::ItemKind;
+use self::{attr::Attribute, item::ItemKind};
 
+pub mod attr;
 pub mod expr;
 pub mod generic;
 pub mod item;
@@ -12,18 +13,21 @@ pub mod pat;
 pub mod stmt;
 pub mod ty;
 
+#[repr(C)]
 #[derive(Debug)]
 pub struct Crate<'ast> {
     id: CrateId,
     items: FfiSlice<'ast, ItemKind<'ast>>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> Crate<'ast> {
-    pub fn new(id: CrateId, items: &'ast [ItemKind<'ast>]) -> Self {
+    pub fn new(id: CrateId, items: &'ast [ItemKind<'ast>], attrs: &'ast [Attribute<'ast>]) -> Self {
         Self {
             id,
             items: items.into(),
+            attrs: attrs.into(),
         }
     }
 }
@@ -39,4 +43,9 @@ impl<'ast> Crate<'ast> {
     pub fn items(&self) -> &[ItemKind<'ast>] {
         self.items.get()
     }
+
+    /// The inner attributes of the crate root, like `#![allow(dead_code)]`.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
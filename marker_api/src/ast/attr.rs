@@ -0,0 +1,421 @@
+//! This module contains the representation of attributes, like `#[must_use]`,
+//! `#[derive(Debug)]` or doc comments.
+//!
+//! See: <https://doc.rust-lang.org/stable/reference/attributes.html>
+
+use std::marker::PhantomData;
+
+use crate::{
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+};
+
+use super::{Ident, Span, SpanId, SymbolId};
+
+/// An attribute attached to a node, like:
+///
+/// ```
+/// #![allow(dead_code)] // An inner attribute
+///
+/// /// A doc comment, which is also an attribute
+/// #[derive(Debug, Clone)] // An outer attribute with arguments
+/// #[must_use] // An outer attribute without arguments
+/// struct Foo;
+/// ```
+///
+/// Attributes from `#[cfg_attr(...)]` have already been expanded when they reach
+/// Marker. Attributes, which are disabled by a `cfg` predicate, are not included.
+///
+/// `#[derive(...)]` attributes are removed by rustc during macro expansion.
+/// Marker reconstructs them from the trait implementations, which they generated.
+/// This is an approximation of the written attribute:
+/// * Derive macros, which don't generate an implementation for the annotated type, are missing from
+///   the arguments.
+/// * An attribute, where no derive macro generated an implementation, is missing entirely.
+/// * The [`DelimitedAttrArgs::tokens`] are generated from the derive names and don't necessarily
+///   match the source code.
+///
+/// See: <https://doc.rust-lang.org/stable/reference/attributes.html>
+#[repr(C)]
+#[derive(Debug)]
+pub struct Attribute<'ast> {
+    span: SpanId,
+    style: AttrStyle,
+    is_doc_comment: bool,
+    path: FfiSlice<'ast, Ident<'ast>>,
+    args: AttrArgs<'ast>,
+}
+
+impl<'ast> Attribute<'ast> {
+    /// The [`Span`] of the entire attribute, including the `#[` and `]` tokens.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+
+    pub fn style(&self) -> AttrStyle {
+        self.style
+    }
+
+    /// Returns `true`, if this is an inner attribute, like `#![allow(dead_code)]`
+    pub fn is_inner(&self) -> bool {
+        matches!(self.style, AttrStyle::Inner)
+    }
+
+    /// Returns `true`, if this is an outer attribute, like `#[must_use]`
+    pub fn is_outer(&self) -> bool {
+        matches!(self.style, AttrStyle::Outer)
+    }
+
+    /// Returns `true`, if this attribute originates from a doc comment, like
+    /// `/// Doc` or `//! Doc`. Doc comments are represented as `#[doc = "..."]`
+    /// attributes. The content can be retrieved via [`doc_str()`](Self::doc_str).
+    pub fn is_doc_comment(&self) -> bool {
+        self.is_doc_comment
+    }
+
+    /// The path of the attribute. This is `derive` for `#[derive(Debug)]` and
+    /// `rustfmt::skip` for `#[rustfmt::skip]`, split into the individual segments.
+    pub fn path(&self) -> &[Ident<'ast>] {
+        self.path.get()
+    }
+
+    /// Returns `true`, if the path of this attribute matches the given path.
+    /// The segments of the path are separated by `::`.
+    ///
+    /// ```ignore
+    /// // Checks for `#[must_use]`
+    /// attr.is_path("must_use");
+    /// // Checks for `#[rustfmt::skip]`
+    /// attr.is_path("rustfmt::skip");
+    /// ```
+    pub fn is_path(&self, path: &str) -> bool {
+        is_path(self.path(), path)
+    }
+
+    pub fn args(&self) -> &AttrArgs<'ast> {
+        &self.args
+    }
+
+    /// Returns the arguments of this attribute, parsed as a list of [`NestedMetaItem`]s,
+    /// if possible. For `#[derive(Debug, Clone)]` this would return the list
+    /// `Debug, Clone`. This returns [`None`], if the attribute has no delimited
+    /// arguments or if they can't be parsed as meta items.
+    pub fn meta_list(&self) -> Option<&[NestedMetaItem<'ast>]> {
+        match &self.args {
+            AttrArgs::Delimited(args) => args.meta(),
+            _ => None,
+        }
+    }
+
+    /// Returns the string value of `#[doc = "..."]` attributes and doc comments.
+    /// Doc comments are returned without the leading `///` or `//!`.
+    pub fn doc_str(&self) -> Option<&str> {
+        match &self.args {
+            AttrArgs::Eq(lit) if self.is_path("doc") => lit.str_value(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Attribute<'ast> {
+    pub fn new(
+        span: SpanId,
+        style: AttrStyle,
+        is_doc_comment: bool,
+        path: &'ast [Ident<'ast>],
+        args: AttrArgs<'ast>,
+    ) -> Self {
+        Self {
+            span,
+            style,
+            is_doc_comment,
+            path: path.into(),
+            args,
+        }
+    }
+}
+
+/// The style of an [`Attribute`], this determines which node the attribute is
+/// attached to.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttrStyle {
+    /// An outer attribute, like `#[must_use]` is attached to the following node.
+    Outer,
+    /// An inner attribute, like `#![allow(dead_code)]` is attached to the
+    /// enclosing node.
+    Inner,
+}
+
+/// The arguments of an [`Attribute`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum AttrArgs<'ast> {
+    /// The attribute has no arguments, like `#[must_use]`
+    Empty,
+    /// The attribute has delimited arguments, like `#[derive(Debug)]` or
+    /// `#[cfg_attr(test, allow(dead_code))]`
+    Delimited(&'ast DelimitedAttrArgs<'ast>),
+    /// The attribute has a value assigned to it, like `#[doc = "Doc"]` or
+    /// `#[path = "file.rs"]`
+    Eq(&'ast MetaLit<'ast>),
+}
+
+/// The delimited arguments of an [`Attribute`], like the `(Debug, Clone)` in
+/// `#[derive(Debug, Clone)]`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DelimitedAttrArgs<'ast> {
+    span: SpanId,
+    delimiter: AttrDelimiter,
+    tokens: SymbolId,
+    meta: FfiOption<FfiSlice<'ast, NestedMetaItem<'ast>>>,
+}
+
+impl<'ast> DelimitedAttrArgs<'ast> {
+    /// The [`Span`] of the arguments, including the delimiters.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+
+    pub fn delimiter(&self) -> AttrDelimiter {
+        self.delimiter
+    }
+
+    /// The arguments as a token string, without the delimiters. Tools can use
+    /// this to parse custom argument formats, which can't be represented as
+    /// [`NestedMetaItem`]s.
+    ///
+    /// For reconstructed `#[derive(...)]` attributes, this is a comma separated
+    /// list of the derive macros, which generated an implementation. See
+    /// [`Attribute`] for more information.
+    pub fn tokens(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.tokens))
+    }
+
+    /// The arguments parsed as a list of [`NestedMetaItem`]s. This returns [`None`],
+    /// if the arguments don't follow the meta item syntax.
+    pub fn meta(&self) -> Option<&[NestedMetaItem<'ast>]> {
+        self.meta.get().map(FfiSlice::get)
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> DelimitedAttrArgs<'ast> {
+    pub fn new(
+        span: SpanId,
+        delimiter: AttrDelimiter,
+        tokens: SymbolId,
+        meta: Option<&'ast [NestedMetaItem<'ast>]>,
+    ) -> Self {
+        Self {
+            span,
+            delimiter,
+            tokens,
+            meta: meta.map(Into::into).into(),
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttrDelimiter {
+    /// `( ... )`
+    Paren,
+    /// `[ ... ]`
+    Bracket,
+    /// `{ ... }`
+    Brace,
+}
+
+/// An element in a list of meta items. This can either be a [`MetaItem`] like
+/// `Debug` in `#[derive(Debug)]` or `note = "reason"` in
+/// `#[deprecated(note = "reason")]`, or a literal like `8` in `#[repr(align(8))]`.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum NestedMetaItem<'ast> {
+    Meta(&'ast MetaItem<'ast>),
+    Lit(&'ast MetaLit<'ast>),
+}
+
+impl<'ast> NestedMetaItem<'ast> {
+    pub fn span(&self) -> &Span<'ast> {
+        match self {
+            NestedMetaItem::Meta(meta) => meta.span(),
+            NestedMetaItem::Lit(lit) => lit.span(),
+        }
+    }
+
+    /// Returns the [`MetaItem`], if this is a meta item and not a literal.
+    pub fn meta_item(&self) -> Option<&'ast MetaItem<'ast>> {
+        match self {
+            NestedMetaItem::Meta(meta) => Some(meta),
+            NestedMetaItem::Lit(_) => None,
+        }
+    }
+}
+
+/// A meta item is the structured form of attribute arguments. There are three
+/// kinds of meta items:
+///
+/// ```text
+/// #[cfg_attr(test, allow(dead_code), path = "test.rs")]
+/// //         ^^^^  ^^^^^^^^^^^^^^^^  ^^^^^^^^^^^^^^^^
+/// //         |     |                 A name value meta item
+/// //         |     A list meta item
+/// //         A word meta item
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct MetaItem<'ast> {
+    span: SpanId,
+    path: FfiSlice<'ast, Ident<'ast>>,
+    kind: MetaItemKind<'ast>,
+}
+
+impl<'ast> MetaItem<'ast> {
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+
+    /// The path of this meta item. This would be `allow` for `allow(dead_code)`
+    /// and `clippy::version` for `clippy::version = "1.0.0"`.
+    pub fn path(&self) -> &[Ident<'ast>] {
+        self.path.get()
+    }
+
+    /// Returns `true`, if the path of this meta item matches the given path.
+    /// The segments of the path are separated by `::`.
+    pub fn is_path(&self, path: &str) -> bool {
+        is_path(self.path(), path)
+    }
+
+    /// Returns `true`, if this meta item is a single path, like `test` or `Debug`
+    pub fn is_word(&self) -> bool {
+        matches!(self.kind, MetaItemKind::Word)
+    }
+
+    /// Returns the nested items, if this is a list meta item like `allow(dead_code)`.
+    pub fn list(&self) -> Option<&[NestedMetaItem<'ast>]> {
+        match &self.kind {
+            MetaItemKind::List(items) => Some(items.get()),
+            _ => None,
+        }
+    }
+
+    /// Returns the assigned value, if this is a name value meta item like
+    /// `path = "test.rs"`.
+    pub fn value(&self) -> Option<&MetaLit<'ast>> {
+        match &self.kind {
+            MetaItemKind::NameValue(lit) => Some(lit),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> MetaItem<'ast> {
+    pub fn new(span: SpanId, path: &'ast [Ident<'ast>], kind: MetaItemKind<'ast>) -> Self {
+        Self {
+            span,
+            path: path.into(),
+            kind,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+#[allow(clippy::exhaustive_enums)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+enum MetaItemKind<'ast> {
+    Word,
+    List(FfiSlice<'ast, NestedMetaItem<'ast>>),
+    NameValue(&'ast MetaLit<'ast>),
+}
+
+/// A literal used inside an [`Attribute`], like the `"Doc"` in `#[doc = "Doc"]`
+/// or the `16` in `#[repr(align(16))]`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct MetaLit<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    span: SpanId,
+    kind: MetaLitKind,
+}
+
+impl<'ast> MetaLit<'ast> {
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+
+    /// Returns the value of string literals, like `"Doc"`.
+    pub fn str_value(&self) -> Option<&str> {
+        match self.kind {
+            MetaLitKind::Str(sym) => Some(with_cx(self, |cx| cx.symbol_str(sym))),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of integer literals, like `16`.
+    pub fn int_value(&self) -> Option<u128> {
+        match self.kind {
+            MetaLitKind::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of boolean literals, like `true`.
+    pub fn bool_value(&self) -> Option<bool> {
+        match self.kind {
+            MetaLitKind::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of character literals, like `'x'`.
+    pub fn char_value(&self) -> Option<char> {
+        match self.kind {
+            MetaLitKind::Char(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> MetaLit<'ast> {
+    pub fn new(span: SpanId, kind: MetaLitKind) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            span,
+            kind,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::exhaustive_enums)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+enum MetaLitKind {
+    Str(SymbolId),
+    Int(u128),
+    Bool(bool),
+    Char(char),
+    /// Other literals, like floats and byte strings, which are currently not
+    /// represented in more detail.
+    Other,
+}
+
+fn is_path(segments: &[Ident<'_>], path: &str) -> bool {
+    let mut path_segs = path.split("::");
+    segments
+        .iter()
+        .all(|seg| path_segs.next().map_or(false, |path_seg| seg.name() == path_seg))
+        && path_segs.next().is_none()
+}
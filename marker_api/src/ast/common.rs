@@ -6,8 +6,12 @@ mod callable;
 pub use callable::*;
 mod ast_path;
 pub use ast_path::*;
-
-use std::{fmt::Debug, marker::PhantomData};
+mod def_path;
+pub use def_path::*;
+mod node;
+pub use node::*;
+mod const_value;
+pub use const_value::*;
 
 use super::generic::SynGenericArgs;
 
@@ -122,27 +126,3 @@ impl Syncness {
         matches!(self, Self::Async)
     }
 }
-
-/// The semantic equivalent of a [`ConstExpr`][super::expr::ConstExpr], at least
-/// theoretically. This part of the API is sadly not done yet, so this is just a
-/// placeholder.
-///
-/// See: rust-marker/marker#179
-#[repr(C)]
-pub struct ConstValue<'ast> {
-    _lifetime: PhantomData<&'ast ()>,
-}
-
-impl<'ast> Debug for ConstValue<'ast> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConstValue {{ /* WIP: See rust-marker/marker#179 */}}")
-            .finish()
-    }
-}
-
-#[cfg(feature = "driver-api")]
-impl<'ast> ConstValue<'ast> {
-    pub fn new() -> Self {
-        Self { _lifetime: PhantomData }
-    }
-}
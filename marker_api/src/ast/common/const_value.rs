@@ -0,0 +1,137 @@
+use crate::{ast::ty::NumKind, ffi::FfiSlice};
+
+/// The value of a constant, which has been evaluated at compile time. This is
+/// the semantic equivalent of a [`ConstExpr`](crate::ast::expr::ConstExpr).
+///
+/// Constant expressions and const items can be evaluated with
+/// [`AstContext::eval_const`](crate::context::AstContext::eval_const):
+///
+/// ```ignore
+/// // Check the size of arrays, declared in statements like: `let x: [u8; 1024];`
+/// if let SynTyKind::Array(array) = ty
+///     && let Some(len) = array.len()
+///     && let ConstValue::Int(len) = cx.eval_const(len.body_id())
+///     && len.to_u128().map_or(false, |len| len > 64 * 1024)
+/// {
+///     // ...
+/// }
+/// ```
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstValue<'ast> {
+    /// An integer value, like `1024_usize` or `-1_i8`.
+    Int(ConstInt),
+    /// A boolean value, `true` or `false`.
+    Bool(bool),
+    /// A character value, like `'x'`.
+    Char(char),
+    /// A string slice, like `"Hello World"`.
+    Str(ConstStr<'ast>),
+    /// A byte string, like `b"Hello World"`. This includes references to byte
+    /// arrays, `&[u8; N]`, and byte slices, `&[u8]`.
+    ByteStr(ConstStr<'ast>),
+    /// The value is too complex to be represented by this enum. This includes
+    /// floats, references, arrays and ADTs. It's also used for values, which
+    /// couldn't be evaluated, for example, because they depend on generic
+    /// parameters.
+    TooComplex,
+}
+
+/// An evaluated integer value, with its integer type.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstInt {
+    /// The bits of the value. Values of signed integers are sign-extended, to
+    /// allow casting them to `i128`.
+    bits: u128,
+    kind: NumKind,
+}
+
+impl ConstInt {
+    /// The type of the integer. This is never a float kind.
+    pub fn kind(&self) -> NumKind {
+        self.kind
+    }
+
+    /// Returns the value as an `u128` or `None` if the value is negative.
+    pub fn to_u128(self) -> Option<u128> {
+        if self.kind.is_signed() {
+            u128::try_from(self.signed_value()).ok()
+        } else {
+            Some(self.bits)
+        }
+    }
+
+    /// Returns the value as an `i128` or `None` if the value is too large.
+    pub fn to_i128(self) -> Option<i128> {
+        if self.kind.is_signed() {
+            Some(self.signed_value())
+        } else {
+            i128::try_from(self.bits).ok()
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)] // The bits of signed values are sign-extended
+    fn signed_value(self) -> i128 {
+        self.bits as i128
+    }
+}
+
+impl std::fmt::Debug for ConstInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("ConstInt");
+        if self.kind.is_signed() {
+            debug.field("value", &self.signed_value());
+        } else {
+            debug.field("value", &self.bits);
+        }
+        debug.field("kind", &self.kind).finish()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl ConstInt {
+    /// The `bits` of signed integers have to be sign-extended.
+    pub fn new(bits: u128, kind: NumKind) -> Self {
+        debug_assert!(kind.is_integer());
+        Self { bits, kind }
+    }
+}
+
+/// The data of an evaluated string or byte string.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstStr<'ast> {
+    bytes: FfiSlice<'ast, u8>,
+}
+
+impl<'ast> ConstStr<'ast> {
+    /// This returns the UTF-8 string value, if possible. Values of
+    /// [`ConstValue::Str`] are always valid UTF-8, byte strings are converted,
+    /// if possible.
+    pub fn str_value(&self) -> Option<&'ast str> {
+        std::str::from_utf8(self.bytes.get()).ok()
+    }
+
+    /// Returns the value as bytes.
+    pub fn byte_value(&self) -> &'ast [u8] {
+        self.bytes.get()
+    }
+}
+
+impl<'ast> std::fmt::Debug for ConstStr<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.str_value() {
+            Some(value) => value.fmt(f),
+            None => self.byte_value().fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ConstStr<'ast> {
+    pub fn new(bytes: &'ast [u8]) -> Self {
+        Self { bytes: bytes.into() }
+    }
+}
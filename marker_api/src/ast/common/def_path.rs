@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use crate::ffi;
+
+use super::{ItemId, TyDefId, VariantId};
+
+/// The id of a definition, which can be converted into a [`DefPath`] via
+/// [`AstContext::def_path`](crate::context::AstContext::def_path).
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefPathId {
+    Item(ItemId),
+    TyDef(TyDefId),
+    Variant(VariantId),
+}
+
+macro_rules! impl_into_def_path_id_for {
+    ($variant:ident, $ty:ty) => {
+        impl From<$ty> for DefPathId {
+            fn from(value: $ty) -> Self {
+                DefPathId::$variant(value)
+            }
+        }
+
+        impl From<&$ty> for DefPathId {
+            fn from(value: &$ty) -> Self {
+                DefPathId::$variant(*value)
+            }
+        }
+    };
+}
+
+impl_into_def_path_id_for!(Item, ItemId);
+impl_into_def_path_id_for!(TyDef, TyDefId);
+impl_into_def_path_id_for!(Variant, VariantId);
+
+/// The human-readable path of a definition, like `my_crate::net::Client::connect`.
+///
+/// The path starts with the name of the crate, that defines the item. This is
+/// also the case for items of the currently linted crate. The [`Display`]
+/// implementation joins the crate name and segments with `::`.
+///
+/// Items inside `impl` blocks use the name of the `Self` type as the segment
+/// for the `impl` block. This is also the case for trait implementations. Other
+/// unnamed definitions, like closures, use a placeholder segment, like
+/// `{closure#0}`.
+///
+/// The path is intended for diagnostic messages and matching against user
+/// provided paths. It's not guaranteed to be a valid Rust path, which can be
+/// used to reference the definition in code. Definitions, which are unknown
+/// to the driver, result in the placeholder path `{unknown}` without segments.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DefPath<'ast> {
+    krate: ffi::FfiStr<'ast>,
+    segments: ffi::FfiSlice<'ast, ffi::FfiStr<'ast>>,
+}
+
+impl<'ast> DefPath<'ast> {
+    /// The name of the crate, that defines the item.
+    pub fn krate(&self) -> &'ast str {
+        self.krate.get()
+    }
+
+    /// The segments of the path, following the crate name.
+    pub fn segments(&self) -> impl Iterator<Item = &'ast str> {
+        self.segments.get().iter().map(ffi::FfiStr::get)
+    }
+}
+
+impl Display for DefPath<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.krate())?;
+        for segment in self.segments() {
+            write!(f, "::{segment}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> DefPath<'ast> {
+    pub fn new(krate: &'ast str, segments: &'ast [ffi::FfiStr<'ast>]) -> Self {
+        Self {
+            krate: krate.into(),
+            segments: segments.into(),
+        }
+    }
+}
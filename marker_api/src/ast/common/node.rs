@@ -0,0 +1,82 @@
+use crate::ast::{
+    expr::ExprKind,
+    item::{Body, EnumVariant, Field, ItemKind},
+    pat::PatKind,
+    stmt::StmtKind,
+};
+
+use super::{BodyId, ExprId, FieldId, ItemId, StmtId, VariantId};
+
+/// The id of a node, which can be used to navigate the AST upwards, with
+/// [`AstContext::parent`](crate::context::AstContext::parent) and
+/// [`AstContext::ancestors`](crate::context::AstContext::ancestors).
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeId {
+    Expr(ExprId),
+    Item(ItemId),
+    Stmt(StmtId),
+    Body(BodyId),
+    Field(FieldId),
+    Variant(VariantId),
+}
+
+macro_rules! impl_into_node_id_for {
+    ($variant:ident, $ty:ty) => {
+        impl From<$ty> for NodeId {
+            fn from(value: $ty) -> Self {
+                NodeId::$variant(value)
+            }
+        }
+
+        impl From<&$ty> for NodeId {
+            fn from(value: &$ty) -> Self {
+                NodeId::$variant(*value)
+            }
+        }
+    };
+}
+
+impl_into_node_id_for!(Expr, ExprId);
+impl_into_node_id_for!(Item, ItemId);
+impl_into_node_id_for!(Stmt, StmtId);
+impl_into_node_id_for!(Body, BodyId);
+impl_into_node_id_for!(Field, FieldId);
+impl_into_node_id_for!(Variant, VariantId);
+
+/// A node of the AST, returned by
+/// [`AstContext::parent`](crate::context::AstContext::parent) and
+/// [`AstContext::ancestors`](crate::context::AstContext::ancestors).
+///
+/// Some syntactic elements, like blocks of `if` expressions or match arms, are
+/// stored as part of their parent nodes. These elements don't have their own
+/// variant and are therefore skipped, when navigating the AST.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum NodeKind<'ast> {
+    Item(ItemKind<'ast>),
+    Body(&'ast Body<'ast>),
+    Stmt(StmtKind<'ast>),
+    Expr(ExprKind<'ast>),
+    Pat(PatKind<'ast>),
+    Field(&'ast Field<'ast>),
+    Variant(&'ast EnumVariant<'ast>),
+}
+
+impl<'ast> NodeKind<'ast> {
+    /// Returns the [`NodeId`] of this node. Patterns don't have an id, this
+    /// will therefore return `None` for [`NodeKind::Pat`].
+    pub fn id(&self) -> Option<NodeId> {
+        match self {
+            NodeKind::Item(node) => Some(node.id().into()),
+            NodeKind::Body(node) => Some(node.id().into()),
+            NodeKind::Stmt(node) => Some(node.id().into()),
+            NodeKind::Expr(node) => Some(node.id().into()),
+            NodeKind::Pat(_) => None,
+            NodeKind::Field(node) => Some(node.id().into()),
+            NodeKind::Variant(node) => Some(node.id().into()),
+        }
+    }
+}
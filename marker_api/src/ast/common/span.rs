@@ -2,12 +2,11 @@ use std::marker::PhantomData;
 
 use crate::{context::with_cx, diagnostic::Applicability, ffi};
 
-use super::{SpanId, SpanSrcId, SymbolId};
+use super::{CrateId, SpanId, SpanSrcId, SymbolId};
 
-// FIXME(xFrednet): This enum is "limited" to say it lightly, it should contain
-// the more information about macros and their expansion etc. This covers the
-// basic use case of checking if a span comes from a macro or a file. The rest
-// will come in due time. Luckily it's not a public enum right now.
+// FIXME(xFrednet): This enum is "limited" to say it lightly. Information about
+// macro expansions is available via [`Span::expn_info`], but the source itself
+// could still be improved. Luckily it's not a public enum right now.
 //
 // See: rust-marker/marker#175
 #[repr(C)]
@@ -44,6 +43,41 @@ impl<'ast> Span<'ast> {
         matches!(self.source, SpanSource::Macro(..))
     }
 
+    /// Returns information about the innermost macro expansion or desugaring,
+    /// that this span originates from. This returns [`None`], if the span comes
+    /// directly from a source file.
+    ///
+    /// Use [`macro_backtrace()`](`Self::macro_backtrace`) to get all expansions,
+    /// which lead to this span.
+    pub fn expn_info(&self) -> Option<&'ast ExpnInfo<'ast>> {
+        match self.source {
+            SpanSource::File(_) => None,
+            SpanSource::Macro(src_id) | SpanSource::Sugar(_, src_id) => with_cx(self, |cx| cx.span_expn_info(*src_id)),
+        }
+    }
+
+    /// Returns an iterator over all expansions, that lead to this span. The
+    /// iterator starts with the innermost expansion, which is the same one
+    /// returned by [`expn_info()`](`Self::expn_info`). Each following element
+    /// is the expansion, which contains the call site of the previous one.
+    ///
+    /// ```ignore
+    /// macro_rules! inner {
+    ///     () => { 1 };
+    /// }
+    /// macro_rules! outer {
+    ///     () => { inner!() };
+    /// }
+    ///
+    /// // The span of the `1` literal has the following backtrace:
+    /// // 1. `inner!()` called in the `outer!` macro
+    /// // 2. `outer!()` called in the source file
+    /// let _ = outer!();
+    /// ```
+    pub fn macro_backtrace(&self) -> impl Iterator<Item = &'ast ExpnInfo<'ast>> {
+        std::iter::successors(self.expn_info(), |info| info.call_site().expn_info())
+    }
+
     /// Returns `true` if the span has a length of 0. This means that no bytes are
     /// inside the span.
     pub fn is_empty(&self) -> bool {
@@ -72,6 +106,15 @@ impl<'ast> Span<'ast> {
         self.end = end;
     }
 
+    /// Returns the location of this span in a source file, containing the file
+    /// path, the line and column numbers and the text of the included lines.
+    ///
+    /// Spans from macro expansions are resolved to the location of the outermost
+    /// macro call site. This returns [`None`], if the source file is unavailable.
+    pub fn file_location(&self) -> Option<&'ast FileLocation<'ast>> {
+        with_cx(self, |cx| cx.span_file_location(self))
+    }
+
     /// Returns the code that this span references or [`None`] if the code is unavailable.
     pub fn snippet(&self) -> Option<String> {
         with_cx(self, |cx| cx.span_snipped(self))
@@ -132,6 +175,211 @@ impl<'ast> Span<'ast> {
     }
 }
 
+/// The location of a [`Span`] in a source file. It can be retrieved via
+/// [`Span::file_location`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct FileLocation<'ast> {
+    file: ffi::FfiStr<'ast>,
+    start: FilePos,
+    end: FilePos,
+    lines: ffi::FfiSlice<'ast, ffi::FfiStr<'ast>>,
+}
+
+impl<'ast> FileLocation<'ast> {
+    /// The path of the source file, as it was passed to the compiler. This
+    /// is usually relative to the directory of the crate root.
+    pub fn file(&self) -> &'ast str {
+        self.file.get()
+    }
+
+    /// The position of the first character of the span.
+    pub fn start(&self) -> FilePos {
+        self.start
+    }
+
+    /// The position directly after the last character of the span.
+    pub fn end(&self) -> FilePos {
+        self.end
+    }
+
+    /// The text of all lines, which are touched by the span. This includes the
+    /// full text of the first and last line, even if the span only covers a part
+    /// of them. Line terminators are not included.
+    pub fn lines(&self) -> impl Iterator<Item = &'ast str> {
+        self.lines.get().iter().map(ffi::FfiStr::get)
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> FileLocation<'ast> {
+    pub fn new(file: &'ast str, start: FilePos, end: FilePos, lines: &'ast [ffi::FfiStr<'ast>]) -> Self {
+        Self {
+            file: file.into(),
+            start,
+            end,
+            lines: lines.into(),
+        }
+    }
+}
+
+/// A position in a source file, described by a line and column.
+///
+/// Lines are 1-based, like the line numbers shown in diagnostics. Columns are
+/// 0-based offsets into the line. They're available in UTF-8 code units (bytes)
+/// and UTF-16 code units. The UTF-16 column is useful for tools, which follow
+/// the conventions of the Language Server Protocol.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FilePos {
+    line: usize,
+    col_utf8: usize,
+    col_utf16: usize,
+}
+
+impl FilePos {
+    /// The 1-based line number.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The 0-based column, counted in UTF-8 code units (bytes).
+    pub fn col_utf8(&self) -> usize {
+        self.col_utf8
+    }
+
+    /// The 0-based column, counted in UTF-16 code units.
+    pub fn col_utf16(&self) -> usize {
+        self.col_utf16
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl FilePos {
+    pub fn new(line: usize, col_utf8: usize, col_utf16: usize) -> Self {
+        Self {
+            line,
+            col_utf8,
+            col_utf16,
+        }
+    }
+}
+
+/// Information about a macro expansion or desugaring, which created a [`Span`].
+/// The expansion information of a span can be retrieved via [`Span::expn_info`].
+#[repr(C)]
+pub struct ExpnInfo<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    kind: ExpnKind,
+    name: SymbolId,
+    def_crate: ffi::FfiOption<CrateId>,
+    def_crate_name: ffi::FfiOption<SymbolId>,
+    is_from_local_crate: bool,
+    call_site: SpanId,
+}
+
+impl<'ast> ExpnInfo<'ast> {
+    pub fn kind(&self) -> ExpnKind {
+        self.kind
+    }
+
+    /// Returns `true`, if this expansion was created by a macro.
+    pub fn is_macro(&self) -> bool {
+        matches!(self.kind, ExpnKind::Bang | ExpnKind::Attr | ExpnKind::Derive)
+    }
+
+    /// The name of the macro, like `vec` for `vec![]` or `Debug` for `#[derive(Debug)]`.
+    /// For desugarings, this is a short description of the desugared construct,
+    /// like ``"`for` loop"``.
+    pub fn name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    /// The crate, which defines the macro. This will be [`None`] for desugarings
+    /// and other expansions, which are not caused by a macro.
+    pub fn def_crate(&self) -> Option<CrateId> {
+        self.def_crate.copy()
+    }
+
+    /// The name of the crate, which defines the macro, like `std` for `vec![]`.
+    /// This will be [`None`] for desugarings and other expansions, which are not
+    /// caused by a macro.
+    pub fn def_crate_name(&self) -> Option<&str> {
+        self.def_crate_name
+            .copy()
+            .map(|sym| with_cx(self, |cx| cx.symbol_str(sym)))
+    }
+
+    /// Returns `true`, if the macro is defined in the crate, that is currently
+    /// being linted.
+    pub fn is_from_local_crate(&self) -> bool {
+        self.is_from_local_crate
+    }
+
+    /// The [`Span`] of the macro call or desugared construct. This span can be
+    /// used for suggestions, since it references the code written by the user.
+    ///
+    /// The call site can itself be the result of another expansion, if the macro
+    /// was called inside another macro. [`Span::macro_backtrace`] can be used to
+    /// iterate over all expansions.
+    pub fn call_site(&self) -> &'ast Span<'ast> {
+        with_cx(self, |cx| cx.span(self.call_site))
+    }
+}
+
+impl<'ast> std::fmt::Debug for ExpnInfo<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExpnInfo")
+            .field("kind", &self.kind)
+            .field("name", &self.name())
+            .field("def_crate", &self.def_crate())
+            .field("def_crate_name", &self.def_crate_name())
+            .field("is_from_local_crate", &self.is_from_local_crate)
+            .field("call_site", &self.call_site())
+            .finish()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ExpnInfo<'ast> {
+    pub fn new(
+        kind: ExpnKind,
+        name: SymbolId,
+        def_crate: Option<CrateId>,
+        def_crate_name: Option<SymbolId>,
+        is_from_local_crate: bool,
+        call_site: SpanId,
+    ) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            kind,
+            name,
+            def_crate: def_crate.into(),
+            def_crate_name: def_crate_name.into(),
+            is_from_local_crate,
+            call_site,
+        }
+    }
+}
+
+/// The kind of an expansion, described by [`ExpnInfo`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpnKind {
+    /// A function-like macro, like `vec![]` or `println!()`
+    Bang,
+    /// An attribute macro, like `#[tokio::main]`
+    Attr,
+    /// A derive macro, like `#[derive(Debug)]`
+    Derive,
+    /// A desugaring done by the compiler, like `for` loops or the `?` operator
+    Desugaring,
+    /// Other expansions, which are done by the compiler, like the injection of
+    /// the standard library prelude.
+    Compiler,
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "driver-api", derive(Clone))]
 pub struct Ident<'ast> {
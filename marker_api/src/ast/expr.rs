@@ -1,6 +1,6 @@
 use crate::{private::Sealed, CtorBlocker};
 
-use super::{ty::SemTyKind, ExprId, Span, SpanId};
+use super::{attr::Attribute, ty::SemTyKind, BodyId, ExprId, Span, SpanId};
 
 use std::{fmt::Debug, marker::PhantomData};
 
@@ -40,6 +40,10 @@ pub trait ExprData<'ast>: Debug + Sealed {
     /// Returns the [`ExprPrecedence`] of this expression.
     fn precedence(&self) -> ExprPrecedence;
 
+    /// Returns the attributes attached to this expression, like the
+    /// `#[rustfmt::skip]` in `let _ = #[rustfmt::skip] (1 + 2);`.
+    fn attrs(&self) -> &[Attribute<'ast>];
+
     /// Returns this expression wrapped in it's [`ExprKind`] variant.
     ///
     /// In function parameters, it's recommended to use `Into<ExprKind<'ast>>`
@@ -91,6 +95,7 @@ impl<'ast> ExprKind<'ast> {
     impl_expr_kind_fn!(ExprKind: id() -> ExprId);
     impl_expr_kind_fn!(ExprKind: ty() -> SemTyKind<'ast>);
     impl_expr_kind_fn!(ExprKind: precedence() -> ExprPrecedence);
+    impl_expr_kind_fn!(ExprKind: attrs() -> &[Attribute<'ast>]);
 }
 
 impl Sealed for ExprKind<'_> {}
@@ -115,6 +120,7 @@ impl<'ast> LitExprKind<'ast> {
     impl_expr_kind_fn!(LitExprKind: id() -> ExprId);
     impl_expr_kind_fn!(LitExprKind: ty() -> SemTyKind<'ast>);
     impl_expr_kind_fn!(LitExprKind: precedence() -> ExprPrecedence);
+    impl_expr_kind_fn!(LitExprKind: attrs() -> &[Attribute<'ast>]);
 }
 
 impl<'ast> From<LitExprKind<'ast>> for ExprKind<'ast> {
@@ -323,6 +329,10 @@ macro_rules! impl_expr_data {
 
             $precedence_fn
 
+            fn attrs(&self) -> &[$crate::ast::attr::Attribute<'ast>] {
+                $crate::context::with_cx(self, |cx| cx.expr_attrs(self.data.id))
+            }
+
             fn as_expr(&'ast self) -> crate::ast::expr::ExprKind<'ast> {
                 $crate::ast::expr::ExprKind::$enum_name(self)
             }
@@ -343,15 +353,22 @@ use impl_expr_data;
 /// An expression that is evaluated at compile time. These show up in array
 /// indices and constant generics.
 ///
-/// Marker currently doesn't provide a way to calculate the result of a constant
-/// expression.
+/// The result of the expression can be calculated with
+/// [`AstContext::eval_const`](crate::context::AstContext::eval_const), by
+/// passing the [`BodyId`] of this expression.
 #[repr(C)]
 #[derive(Debug)]
 pub struct ConstExpr<'ast> {
+    body_id: BodyId,
     expr: ExprKind<'ast>,
 }
 
 impl<'ast> ConstExpr<'ast> {
+    /// The [`BodyId`] of the constant, which contains the wrapped expression.
+    pub fn body_id(&self) -> BodyId {
+        self.body_id
+    }
+
     /// This returns the wrapped expression that will be evaluated at compile time.
     pub fn expr(&self) -> ExprKind<'ast> {
         self.expr
@@ -360,8 +377,8 @@ impl<'ast> ConstExpr<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> ConstExpr<'ast> {
-    pub fn new(expr: ExprKind<'ast>) -> Self {
-        Self { expr }
+    pub fn new(body_id: BodyId, expr: ExprKind<'ast>) -> Self {
+        Self { body_id, expr }
     }
 }
 
@@ -390,7 +407,7 @@ mod test {
         assert_eq!(96, size_of::<PathExpr<'_>>(), "PathExpr<'_>");
         assert_eq!(48, size_of::<CallExpr<'_>>(), "CallExpr<'_>");
         assert_eq!(80, size_of::<MethodExpr<'_>>(), "MethodExpr<'_>");
-        assert_eq!(56, size_of::<ArrayExpr<'_>>(), "ArrayExpr<'_>");
+        assert_eq!(64, size_of::<ArrayExpr<'_>>(), "ArrayExpr<'_>");
         assert_eq!(32, size_of::<TupleExpr<'_>>(), "TupleExpr<'_>");
         assert_eq!(136, size_of::<CtorExpr<'_>>(), "CtorExpr<'_>");
         assert_eq!(72, size_of::<RangeExpr<'_>>(), "RangeExpr<'_>");
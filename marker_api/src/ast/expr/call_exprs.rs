@@ -1,4 +1,8 @@
-use crate::{ast::AstPathSegment, ffi::FfiSlice};
+use crate::{
+    ast::{generic::SemGenericArgs, AstPathSegment, ItemId, TyDefId},
+    context::with_cx,
+    ffi::FfiSlice,
+};
 
 use super::{CommonExprData, ExprKind};
 
@@ -73,14 +77,12 @@ impl<'ast> MethodExpr<'ast> {
         &self.method
     }
 
-    // FIXME(xFrednet): Add this method again, once `resolve_method_target` is
-    // supported by rustc's driver
-    //
-    // /// This method resolves the [`ItemId`] of the method being called by this
-    // /// expression.
-    // pub fn resolve(&self) -> ItemId {
-    //     with_cx(self, |cx| cx.resolve_method_target(self.data.id))
-    // }
+    /// This resolves the method, being called by this expression. The returned
+    /// [`MethodTarget`] also contains the `impl` block or trait, that the method
+    /// belongs to, and the generic arguments used for the call.
+    pub fn resolve(&self) -> &'ast MethodTarget<'ast> {
+        with_cx(self, |cx| cx.resolve_method_target(self.data.id))
+    }
 
     /// The arguments given to the operand.
     pub fn args(&self) -> &[ExprKind<'ast>] {
@@ -106,3 +108,75 @@ impl<'ast> MethodExpr<'ast> {
         }
     }
 }
+
+/// The resolved target of a [`MethodExpr`].
+///
+/// ```
+/// # #[derive(Clone, Default)]
+/// # struct Sheep;
+/// # impl Sheep {
+/// #     fn shear(&mut self, _: u32) {}
+/// # }
+/// let mut sheep = Sheep::default();
+/// // The target of this call is `Sheep::shear`, from the inherent `impl Sheep` block
+/// sheep.shear(3);
+/// // The target of this call is `<Sheep as Clone>::clone`, from the derived
+/// // `impl Clone for Sheep` block
+/// let _dolly = sheep.clone();
+/// ```
+///
+/// Calls of trait methods are resolved to the implementation, if the type of
+/// the receiver is known. Calls on generic types and calls of default methods,
+/// which are not overridden by the implementation, resolve to the trait method.
+#[repr(C)]
+#[derive(Debug)]
+pub struct MethodTarget<'ast> {
+    method: ItemId,
+    source: MethodSource,
+    generics: SemGenericArgs<'ast>,
+}
+
+impl<'ast> MethodTarget<'ast> {
+    /// The [`ItemId`] of the called method. The method can be defined in the current
+    /// crate or in a dependency.
+    pub fn method(&self) -> ItemId {
+        self.method
+    }
+
+    /// The `impl` block or trait, that the method belongs to.
+    pub fn source(&self) -> MethodSource {
+        self.source
+    }
+
+    /// The generic arguments used for this call. This includes arguments for the
+    /// generic parameters of the [`MethodSource`], followed by the arguments for the
+    /// parameters of the method. For trait methods, the first argument is the type
+    /// of `Self`.
+    pub fn generics(&self) -> &SemGenericArgs<'ast> {
+        &self.generics
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> MethodTarget<'ast> {
+    pub fn new(method: ItemId, source: MethodSource, generics: SemGenericArgs<'ast>) -> Self {
+        Self {
+            method,
+            source,
+            generics,
+        }
+    }
+}
+
+/// The item, which provides a resolved method. See [`MethodTarget::source`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MethodSource {
+    /// The method is defined in an `impl` block, identified by the given [`ItemId`].
+    /// This can be an inherent `impl` block or a trait implementation.
+    Impl(ItemId),
+    /// The method is provided by the trait with the given [`TyDefId`]. This is the
+    /// case for calls on generic types and for default methods of the trait.
+    Trait(TyDefId),
+}
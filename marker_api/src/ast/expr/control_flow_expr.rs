@@ -1,5 +1,5 @@
 use crate::{
-    ast::{pat::PatKind, ExprId, Ident, Span, SpanId},
+    ast::{attr::Attribute, pat::PatKind, ExprId, Ident, Span, SpanId},
     context::with_cx,
     ffi::{FfiOption, FfiSlice},
 };
@@ -198,6 +198,7 @@ pub struct MatchArm<'ast> {
     pat: PatKind<'ast>,
     guard: FfiOption<ExprKind<'ast>>,
     expr: ExprKind<'ast>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 impl<'ast> MatchArm<'ast> {
@@ -217,17 +218,26 @@ impl<'ast> MatchArm<'ast> {
         self.expr
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> MatchArm<'ast> {
-    pub fn new(span: SpanId, pat: PatKind<'ast>, guard: Option<ExprKind<'ast>>, expr: ExprKind<'ast>) -> Self {
+    pub fn new(
+        span: SpanId,
+        pat: PatKind<'ast>,
+        guard: Option<ExprKind<'ast>>,
+        expr: ExprKind<'ast>,
+        attrs: &'ast [Attribute<'ast>],
+    ) -> Self {
         Self {
             span,
             pat,
             guard: guard.into(),
             expr,
+            attrs: attrs.into(),
         }
     }
 }
@@ -1,4 +1,7 @@
-use crate::ast::TyDefId;
+use std::marker::PhantomData;
+
+use crate::ast::{GenericId, SymbolId, TyDefId};
+use crate::context::with_cx;
 
 use super::SemGenericArgs;
 
@@ -40,3 +43,49 @@ impl<'ast> SemTraitBound<'ast> {
         }
     }
 }
+
+/// A generic parameter declared by an item, as seen by the compiler. This is used
+/// by the semantic item view [`SemItem`](crate::ast::item::SemItem).
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemGenericParam<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    id: GenericId,
+    name: SymbolId,
+    kind: SemGenericParamKind,
+}
+
+impl<'ast> SemGenericParam<'ast> {
+    pub fn id(&self) -> GenericId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    pub fn kind(&self) -> SemGenericParamKind {
+        self.kind
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemGenericParam<'ast> {
+    pub fn new(id: GenericId, name: SymbolId, kind: SemGenericParamKind) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            id,
+            name,
+            kind,
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SemGenericParamKind {
+    Lifetime,
+    Ty,
+    Const,
+}
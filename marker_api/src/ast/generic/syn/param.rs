@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use crate::ast::attr::Attribute;
 use crate::ast::expr::ConstExpr;
 use crate::ast::generic::{Lifetime, SynGenericParams};
 use crate::ast::ty::SynTyKind;
@@ -55,6 +56,15 @@ impl<'ast> SynGenericParamKind<'ast> {
             SynGenericParamKind::Const(param) => param.id(),
         }
     }
+
+    /// The attributes attached to this parameter.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        match self {
+            SynGenericParamKind::Lifetime(lt) => lt.attrs(),
+            SynGenericParamKind::Ty(ty) => ty.attrs(),
+            SynGenericParamKind::Const(con) => con.attrs(),
+        }
+    }
 }
 
 /// This trait is a collection of common information that is provided by all
@@ -67,7 +77,8 @@ pub trait SynGenericParamData<'ast>: Debug + Sealed {
     /// code.
     fn span(&self) -> Option<&Span<'ast>>;
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The attributes attached to this parameter.
+    fn attrs(&self) -> &[Attribute<'ast>];
 }
 
 /// A type parameter with optional bounds like `T` and `U` in this example:
@@ -84,16 +95,18 @@ pub struct SynTyParam<'ast> {
     id: GenericId,
     name: SymbolId,
     span: FfiOption<SpanId>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SynTyParam<'ast> {
-    pub fn new(span: Option<SpanId>, name: SymbolId, id: GenericId) -> Self {
+    pub fn new(span: Option<SpanId>, name: SymbolId, id: GenericId, attrs: &'ast [Attribute<'ast>]) -> Self {
         Self {
             _data: PhantomData,
             id,
             name,
             span: span.into(),
+            attrs: attrs.into(),
         }
     }
 }
@@ -112,6 +125,10 @@ impl<'ast> SynGenericParamData<'ast> for SynTyParam<'ast> {
     fn span(&self) -> Option<&Span<'ast>> {
         self.span.get().map(|span| with_cx(self, |cx| cx.span(*span)))
     }
+
+    fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
 
 impl Sealed for SynTyParam<'_> {}
@@ -136,16 +153,18 @@ pub struct SynLifetimeParam<'ast> {
     id: GenericId,
     name: SymbolId,
     span: FfiOption<SpanId>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SynLifetimeParam<'ast> {
-    pub fn new(id: GenericId, name: SymbolId, span: Option<SpanId>) -> Self {
+    pub fn new(id: GenericId, name: SymbolId, span: Option<SpanId>, attrs: &'ast [Attribute<'ast>]) -> Self {
         Self {
             _data: PhantomData,
             id,
             name,
             span: span.into(),
+            attrs: attrs.into(),
         }
     }
 }
@@ -164,6 +183,10 @@ impl<'ast> SynGenericParamData<'ast> for SynLifetimeParam<'ast> {
     fn span(&self) -> Option<&Span<'ast>> {
         self.span.get().map(|span| with_cx(self, |cx| cx.span(*span)))
     }
+
+    fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
 
 impl Sealed for SynLifetimeParam<'_> {}
@@ -188,6 +211,7 @@ pub struct SynConstParam<'ast> {
     ty: SynTyKind<'ast>,
     default: FfiOption<ConstExpr<'ast>>,
     span: SpanId,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 impl<'ast> SynConstParam<'ast> {
@@ -214,6 +238,10 @@ impl<'ast> SynGenericParamData<'ast> for SynConstParam<'ast> {
     fn span(&self) -> Option<&Span<'ast>> {
         Some(with_cx(self, |cx| cx.span(self.span)))
     }
+
+    fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -224,6 +252,7 @@ impl<'ast> SynConstParam<'ast> {
         ty: SynTyKind<'ast>,
         default: Option<ConstExpr<'ast>>,
         span: SpanId,
+        attrs: &'ast [Attribute<'ast>],
     ) -> Self {
         Self {
             id,
@@ -231,6 +260,7 @@ impl<'ast> SynConstParam<'ast> {
             ty,
             default: default.into(),
             span,
+            attrs: attrs.into(),
         }
     }
 }
@@ -1,10 +1,13 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use crate::context::with_cx;
+use crate::ffi::{FfiOption, FfiSlice};
 use crate::private::Sealed;
 use crate::CtorBlocker;
 
+use super::attr::Attribute;
 use super::expr::ExprKind;
-use super::{Ident, ItemId, Span, SpanId};
+use super::{BodyId, Ident, ItemId, Span, SpanId};
 
 // Item implementations
 mod extern_crate_item;
@@ -31,6 +34,8 @@ mod extern_block_item;
 pub use extern_block_item::*;
 mod unstable_item;
 pub use unstable_item::*;
+mod sem_item;
+pub use sem_item::*;
 
 /// This trait combines methods, which are common between all items.
 ///
@@ -57,12 +62,9 @@ pub trait ItemData<'ast>: Debug + Sealed {
     /// as a bound to support all items and `ItemKind<'ast>` as parameters.
     fn as_item(&'ast self) -> ItemKind<'ast>;
 
-    /// The attributes attached to this item.
-    ///
-    /// Currently, it's only a placeholder until a proper representation is implemented.
-    /// rust-marker/marker#51 tracks the task of implementing this. You're welcome to
-    /// leave any comments in that issue.
-    fn attrs(&self); // FIXME: Add return type: -> &'ast [&'ast dyn Attribute<'ast>];
+    /// The attributes attached to this item. This includes outer attributes, like
+    /// `#[must_use]`, and inner attributes, like `#![allow(dead_code)]` inside a module.
+    fn attrs(&self) -> &[Attribute<'ast>];
 }
 
 #[repr(C)]
@@ -90,7 +92,7 @@ impl<'ast> ItemKind<'ast> {
     impl_item_type_fn!(ItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(ItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(ItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(ItemKind: attrs() -> ());
+    impl_item_type_fn!(ItemKind: attrs() -> &[Attribute<'ast>]);
 }
 
 #[non_exhaustive]
@@ -106,7 +108,7 @@ impl<'ast> AssocItemKind<'ast> {
     impl_item_type_fn!(AssocItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(AssocItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(AssocItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(AssocItemKind: attrs() -> ());
+    impl_item_type_fn!(AssocItemKind: attrs() -> &[Attribute<'ast>]);
     impl_item_type_fn!(AssocItemKind: as_item() -> ItemKind<'ast>);
     // FIXME: Potentially add a field to the items to optionally store the owner id
 }
@@ -133,7 +135,7 @@ impl<'ast> ExternItemKind<'ast> {
     impl_item_type_fn!(ExternItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(ExternItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(ExternItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(ExternItemKind: attrs() -> ());
+    impl_item_type_fn!(ExternItemKind: attrs() -> &[Attribute<'ast>]);
     impl_item_type_fn!(ExternItemKind: as_item() -> ItemKind<'ast>);
 }
 
@@ -184,6 +186,7 @@ struct CommonItemData<'ast> {
     span: SpanId,
     vis: Visibility<'ast>,
     ident: Ident<'ast>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 macro_rules! impl_item_data {
@@ -209,7 +212,9 @@ macro_rules! impl_item_data {
                 $crate::ast::item::ItemKind::$enum_name(self)
             }
 
-            fn attrs(&self) {}
+            fn attrs(&self) -> &[crate::ast::attr::Attribute<'ast>] {
+                self.data.attrs.get()
+            }
         }
 
         impl $crate::private::Sealed for $self_name<'_> {}
@@ -226,44 +231,147 @@ use impl_item_data;
 
 #[cfg(feature = "driver-api")]
 impl<'ast> CommonItemData<'ast> {
-    pub fn new(id: ItemId, span: SpanId, ident: Ident<'ast>) -> Self {
+    pub fn new(
+        id: ItemId,
+        span: SpanId,
+        vis: Visibility<'ast>,
+        ident: Ident<'ast>,
+        attrs: &'ast [Attribute<'ast>],
+    ) -> Self {
         Self {
             id,
             span,
-            vis: Visibility::new(id),
+            vis,
             ident,
+            attrs: attrs.into(),
         }
     }
 }
 
-/// This struct represents the visibility of an item.
+/// The visibility of an item or field, like the `pub(crate)` in:
+///
+/// ```
+/// # mod example {
+/// pub(crate) struct Foo {
+/// //^^^^^^^^ The visibility of the struct
+///     pub field: u32,
+/// //  ^^^ The visibility of the field
+///     other: u32,
+/// //  The visibility of this field is inherited (private)
+/// }
+/// # }
+/// ```
+///
+/// This is a syntactic representation, based on what has been written in the
+/// source code. The [`Visibility::is_exported`] function can be used, to check if
+/// the item can actually be reached from outside the crate.
 ///
-/// Currently, it's only a placeholder until a proper representation is implemented.
-/// rust-marker/marker#26 tracks the task of implementing this. You're welcome to
-/// leave any comments in that issue.
+/// See: <https://doc.rust-lang.org/reference/visibility-and-privacy.html>
 #[repr(C)]
 pub struct Visibility<'ast> {
     _lifetime: PhantomData<&'ast ()>,
-    _item_id: ItemId,
+    item_id: ItemId,
+    span: FfiOption<SpanId>,
+    kind: VisibilityKind,
+}
+
+impl<'ast> Visibility<'ast> {
+    /// Returns the [`VisibilityKind`] of this visibility.
+    pub fn kind(&self) -> VisibilityKind {
+        self.kind
+    }
+
+    /// Returns `true`, if this visibility is `pub`.
+    pub fn is_pub(&self) -> bool {
+        matches!(self.kind, VisibilityKind::Public)
+    }
+
+    /// Returns `true`, if this visibility is `pub(crate)`.
+    pub fn is_pub_crate(&self) -> bool {
+        matches!(self.kind, VisibilityKind::Crate)
+    }
+
+    /// Returns `true`, if no visibility has been declared.
+    pub fn is_inherited(&self) -> bool {
+        matches!(self.kind, VisibilityKind::Inherited)
+    }
+
+    /// Returns the [`Span`] of the written visibility, like `pub(crate)`.
+    /// This will be [`None`] for inherited visibilities.
+    pub fn span(&self) -> Option<&Span<'ast>> {
+        self.span.get().map(|span| with_cx(self, |cx| cx.span(*span)))
+    }
+
+    /// Returns `true`, if the item or field with this visibility can be named
+    /// from outside the crate, either directly or through a `pub use` reexport.
+    ///
+    /// A `pub` item inside a private module will return `false`, unless it's
+    /// reexported by a public module:
+    ///
+    /// ```
+    /// mod private {
+    ///     pub fn not_exported() {}
+    ///     pub fn exported() {}
+    /// }
+    ///
+    /// pub use private::exported;
+    /// # fn main() {}
+    /// ```
+    pub fn is_exported(&self) -> bool {
+        with_cx(self, |cx| cx.is_exported(self.item_id))
+    }
 }
 
 impl<'ast> Debug for Visibility<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Visibility {{ /* WIP: See rust-marker/marker#26 */}}")
+        f.debug_struct("Visibility")
+            .field("kind", &self.kind)
+            .field("span", &self.span())
             .finish()
     }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> Visibility<'ast> {
-    pub fn new(item_id: ItemId) -> Self {
+    pub fn new(item_id: ItemId, span: Option<SpanId>, kind: VisibilityKind) -> Self {
         Self {
             _lifetime: PhantomData,
-            _item_id: item_id,
+            item_id,
+            span: span.into(),
+            kind,
         }
     }
 }
 
+/// The different kinds of [`Visibility`], which can be declared in Rust.
+///
+/// Restricted visibilities are represented by the module, that the item is
+/// restricted to. Declarations which restrict the item to the same module are
+/// represented the same way. For example, `pub(super)` in a direct submodule of
+/// the crate root is represented as [`VisibilityKind::Crate`], since it's
+/// equivalent to `pub(crate)`.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VisibilityKind {
+    /// The item is public, declared by `pub`.
+    Public,
+    /// The item is visible in the current crate, declared by `pub(crate)` or
+    /// `pub(in crate)`.
+    Crate,
+    /// The item is visible in the parent module, declared by `pub(super)`.
+    Super,
+    /// The item is visible in the given module, declared by `pub(in path)`
+    /// or `pub(self)`. The [`ItemId`] belongs to the module, which the
+    /// visibility is restricted to.
+    Path(ItemId),
+    /// No visibility has been declared. Items and fields are private in this
+    /// case, meaning that they're only visible in the current module.
+    /// Enum variants, trait items and items in trait implementations don't
+    /// have their own visibility, they use the visibility of their parent.
+    Inherited,
+}
+
 /// A body represents the expression of items.
 ///
 /// Bodies act like a barrier between the item and expression level. When items
@@ -274,11 +382,16 @@ impl<'ast> Visibility<'ast> {
 #[repr(C)]
 #[derive(Debug)]
 pub struct Body<'ast> {
+    id: BodyId,
     owner: ItemId,
     expr: ExprKind<'ast>,
 }
 
 impl<'ast> Body<'ast> {
+    pub fn id(&self) -> BodyId {
+        self.id
+    }
+
     pub fn owner(&self) -> ItemId {
         self.owner
     }
@@ -292,8 +405,8 @@ impl<'ast> Body<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> Body<'ast> {
-    pub fn new(owner: ItemId, expr: ExprKind<'ast>) -> Self {
-        Self { owner, expr }
+    pub fn new(id: BodyId, owner: ItemId, expr: ExprKind<'ast>) -> Self {
+        Self { id, owner, expr }
     }
 }
 
@@ -306,19 +419,19 @@ mod test {
     fn test_item_struct_size() {
         // These sizes are allowed to change, this is just a check to have a
         // general overview and to prevent accidental changes
-        assert_eq!(56, size_of::<ModItem<'_>>(), "ModItem");
-        assert_eq!(48, size_of::<ExternCrateItem<'_>>(), "ExternCrateItem");
-        assert_eq!(64, size_of::<UseItem<'_>>(), "UseItem");
-        assert_eq!(80, size_of::<StaticItem<'_>>(), "StaticItem");
-        assert_eq!(72, size_of::<ConstItem<'_>>(), "ConstItem");
-        assert_eq!(144, size_of::<FnItem<'_>>(), "FnItem");
-        assert_eq!(112, size_of::<TyAliasItem<'_>>(), "TyAliasItem");
-        assert_eq!(96, size_of::<StructItem<'_>>(), "StructItem");
-        assert_eq!(88, size_of::<EnumItem<'_>>(), "EnumItem");
-        assert_eq!(88, size_of::<UnionItem<'_>>(), "UnionItem");
-        assert_eq!(112, size_of::<TraitItem<'_>>(), "TraitItem");
-        assert_eq!(144, size_of::<ImplItem<'_>>(), "ImplItem");
-        assert_eq!(64, size_of::<ExternBlockItem<'_>>(), "ExternBlockItem");
-        assert_eq!(48, size_of::<UnstableItem<'_>>(), "UnstableItem");
+        assert_eq!(104, size_of::<ModItem<'_>>(), "ModItem");
+        assert_eq!(96, size_of::<ExternCrateItem<'_>>(), "ExternCrateItem");
+        assert_eq!(112, size_of::<UseItem<'_>>(), "UseItem");
+        assert_eq!(128, size_of::<StaticItem<'_>>(), "StaticItem");
+        assert_eq!(120, size_of::<ConstItem<'_>>(), "ConstItem");
+        assert_eq!(192, size_of::<FnItem<'_>>(), "FnItem");
+        assert_eq!(160, size_of::<TyAliasItem<'_>>(), "TyAliasItem");
+        assert_eq!(144, size_of::<StructItem<'_>>(), "StructItem");
+        assert_eq!(136, size_of::<EnumItem<'_>>(), "EnumItem");
+        assert_eq!(136, size_of::<UnionItem<'_>>(), "UnionItem");
+        assert_eq!(160, size_of::<TraitItem<'_>>(), "TraitItem");
+        assert_eq!(192, size_of::<ImplItem<'_>>(), "ImplItem");
+        assert_eq!(112, size_of::<ExternBlockItem<'_>>(), "ExternBlockItem");
+        assert_eq!(96, size_of::<UnstableItem<'_>>(), "UnstableItem");
     }
 }
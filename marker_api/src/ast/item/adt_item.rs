@@ -1,3 +1,4 @@
+use crate::ast::attr::Attribute;
 use crate::ast::expr::ConstExpr;
 use crate::ast::generic::SynGenericParams;
 use crate::ast::ty::SynTyKind;
@@ -103,6 +104,7 @@ pub struct EnumVariant<'ast> {
     span: SpanId,
     kind: AdtKind<'ast>,
     discriminant: FfiOption<ConstExpr<'ast>>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 impl<'ast> EnumVariant<'ast> {
@@ -114,7 +116,10 @@ impl<'ast> EnumVariant<'ast> {
         with_cx(self, |cx| cx.symbol_str(self.ident))
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The attributes attached to this variant.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 
     /// Returns `true` if this is a unit variant like:
     ///
@@ -179,6 +184,7 @@ impl<'ast> EnumVariant<'ast> {
         span: SpanId,
         kind: AdtKind<'ast>,
         discriminant: Option<ConstExpr<'ast>>,
+        attrs: &'ast [Attribute<'ast>],
     ) -> Self {
         Self {
             id,
@@ -186,6 +192,7 @@ impl<'ast> EnumVariant<'ast> {
             span,
             kind,
             discriminant: discriminant.into(),
+            attrs: attrs.into(),
         }
     }
 }
@@ -290,6 +297,7 @@ pub struct Field<'ast> {
     ident: SymbolId,
     ty: SynTyKind<'ast>,
     span: SpanId,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 impl<'ast> Field<'ast> {
@@ -316,18 +324,29 @@ impl<'ast> Field<'ast> {
         with_cx(self, |cx| cx.span(self.span))
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The attributes attached to this field.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> Field<'ast> {
-    pub fn new(id: FieldId, vis: Visibility<'ast>, ident: SymbolId, ty: SynTyKind<'ast>, span: SpanId) -> Self {
+    pub fn new(
+        id: FieldId,
+        vis: Visibility<'ast>,
+        ident: SymbolId,
+        ty: SynTyKind<'ast>,
+        span: SpanId,
+        attrs: &'ast [Attribute<'ast>],
+    ) -> Self {
         Self {
             id,
             vis,
             ident,
             ty,
             span,
+            attrs: attrs.into(),
         }
     }
 }
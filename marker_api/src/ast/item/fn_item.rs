@@ -1,3 +1,4 @@
+use crate::ast::attr::Attribute;
 use crate::ast::generic::SynGenericParams;
 use crate::ast::pat::PatKind;
 use crate::ast::ty::SynTyKind;
@@ -170,6 +171,7 @@ pub struct FnParam<'ast> {
     span: SpanId,
     pat: PatKind<'ast>,
     ty: SynTyKind<'ast>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 impl<'ast> FnParam<'ast> {
@@ -184,11 +186,20 @@ impl<'ast> FnParam<'ast> {
     pub fn ty(&self) -> SynTyKind<'ast> {
         self.ty
     }
+
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> FnParam<'ast> {
-    pub fn new(span: SpanId, pat: PatKind<'ast>, ty: SynTyKind<'ast>) -> Self {
-        Self { span, pat, ty }
+    pub fn new(span: SpanId, pat: PatKind<'ast>, ty: SynTyKind<'ast>, attrs: &'ast [Attribute<'ast>]) -> Self {
+        Self {
+            span,
+            pat,
+            ty,
+            attrs: attrs.into(),
+        }
     }
 }
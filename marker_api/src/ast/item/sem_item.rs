@@ -0,0 +1,456 @@
+use std::marker::PhantomData;
+
+use crate::ast::attr::Attribute;
+use crate::ast::generic::SemGenericParam;
+use crate::ast::ty::SemTyKind;
+use crate::ast::{Constness, Mutability, Safety, SymbolId, Syncness, TyDefId};
+use crate::context::with_cx;
+use crate::ffi::{FfiOption, FfiSlice};
+
+use super::{ItemId, VisibilityKind};
+
+/// The semantic view of an item, based on the type information of the compiler.
+///
+/// In contrast to [`ItemKind`](super::ItemKind), this view is available for local
+/// items and items from dependencies. It can be retrieved via
+/// [`AstContext::sem_item`](crate::context::AstContext::sem_item).
+///
+/// Items from dependencies don't have a syntactic representation, this view
+/// therefore doesn't contain any spans, syntactic types or bodies.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemItem<'ast> {
+    id: ItemId,
+    name: FfiOption<SymbolId>,
+    visibility: VisibilityKind,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
+    generics: FfiSlice<'ast, SemGenericParam<'ast>>,
+    kind: SemItemKind<'ast>,
+}
+
+impl<'ast> SemItem<'ast> {
+    pub fn id(&self) -> ItemId {
+        self.id
+    }
+
+    /// The name of the item. This will be `None` for items without names, like
+    /// `impl` blocks.
+    pub fn name(&self) -> Option<&str> {
+        self.name.get().map(|name| with_cx(self, |cx| cx.symbol_str(*name)))
+    }
+
+    /// The visibility of the item, as seen by the compiler. Restricted visibilities,
+    /// like `pub(super)`, are represented by [`VisibilityKind::Path`] with the
+    /// module the item is restricted to. [`VisibilityKind::Crate`] is used, if
+    /// the module is the crate root.
+    pub fn visibility(&self) -> VisibilityKind {
+        self.visibility
+    }
+
+    /// The attributes attached to this item. Dependencies only store a subset of
+    /// the attributes, which are relevant for other crates, like `#[non_exhaustive]`
+    /// or `#[must_use]`.
+    pub fn attrs(&self) -> &'ast [Attribute<'ast>] {
+        self.attrs.get()
+    }
+
+    /// The generic parameters declared by this item. Parameters inherited from
+    /// parent items, like the `impl` block of a method, are not included.
+    pub fn generics(&self) -> &'ast [SemGenericParam<'ast>] {
+        self.generics.get()
+    }
+
+    pub fn kind(&self) -> SemItemKind<'ast> {
+        self.kind
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemItem<'ast> {
+    pub fn new(
+        id: ItemId,
+        name: Option<SymbolId>,
+        visibility: VisibilityKind,
+        attrs: &'ast [Attribute<'ast>],
+        generics: &'ast [SemGenericParam<'ast>],
+        kind: SemItemKind<'ast>,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            visibility,
+            attrs: attrs.into(),
+            generics: generics.into(),
+            kind,
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum SemItemKind<'ast> {
+    Mod(&'ast SemModItem<'ast>),
+    Fn(&'ast SemFnItem<'ast>),
+    Const(&'ast SemConstItem<'ast>),
+    Static(&'ast SemStaticItem<'ast>),
+    TyAlias(&'ast SemTyAliasItem<'ast>),
+    Adt(&'ast SemAdtItem<'ast>),
+    Trait(&'ast SemTraitItem<'ast>),
+    Impl(&'ast SemImplItem<'ast>),
+    /// Items, which are not represented by this view yet, like macros.
+    Other,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemModItem<'ast> {
+    items: FfiSlice<'ast, ItemId>,
+}
+
+impl<'ast> SemModItem<'ast> {
+    /// The items, which can be named through this module. This includes items,
+    /// which are reexported by this module.
+    pub fn items(&self) -> &'ast [ItemId] {
+        self.items.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemModItem<'ast> {
+    pub fn new(items: &'ast [ItemId]) -> Self {
+        Self { items: items.into() }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemFnItem<'ast> {
+    params: FfiSlice<'ast, SemTyKind<'ast>>,
+    return_ty: SemTyKind<'ast>,
+    constness: Constness,
+    syncness: Syncness,
+    safety: Safety,
+    has_self: bool,
+}
+
+impl<'ast> SemFnItem<'ast> {
+    /// The types of the parameters. This includes the `self` parameter of
+    /// methods.
+    pub fn params(&self) -> &'ast [SemTyKind<'ast>] {
+        self.params.get()
+    }
+
+    pub fn return_ty(&self) -> SemTyKind<'ast> {
+        self.return_ty
+    }
+
+    pub fn constness(&self) -> Constness {
+        self.constness
+    }
+
+    pub fn syncness(&self) -> Syncness {
+        self.syncness
+    }
+
+    pub fn safety(&self) -> Safety {
+        self.safety
+    }
+
+    /// Returns `true`, if this is an associated function with a `self` parameter.
+    pub fn has_self(&self) -> bool {
+        self.has_self
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemFnItem<'ast> {
+    pub fn new(
+        params: &'ast [SemTyKind<'ast>],
+        return_ty: SemTyKind<'ast>,
+        constness: Constness,
+        syncness: Syncness,
+        safety: Safety,
+        has_self: bool,
+    ) -> Self {
+        Self {
+            params: params.into(),
+            return_ty,
+            constness,
+            syncness,
+            safety,
+            has_self,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemConstItem<'ast> {
+    ty: SemTyKind<'ast>,
+}
+
+impl<'ast> SemConstItem<'ast> {
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        self.ty
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemConstItem<'ast> {
+    pub fn new(ty: SemTyKind<'ast>) -> Self {
+        Self { ty }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemStaticItem<'ast> {
+    ty: SemTyKind<'ast>,
+    mutability: Mutability,
+}
+
+impl<'ast> SemStaticItem<'ast> {
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        self.ty
+    }
+
+    pub fn mutability(&self) -> Mutability {
+        self.mutability
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemStaticItem<'ast> {
+    pub fn new(ty: SemTyKind<'ast>, mutability: Mutability) -> Self {
+        Self { ty, mutability }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemTyAliasItem<'ast> {
+    aliased_ty: FfiOption<SemTyKind<'ast>>,
+}
+
+impl<'ast> SemTyAliasItem<'ast> {
+    /// The aliased type. This will be `None` for associated types of traits,
+    /// which don't declare a default type.
+    pub fn aliased_ty(&self) -> Option<SemTyKind<'ast>> {
+        self.aliased_ty.copy()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemTyAliasItem<'ast> {
+    pub fn new(aliased_ty: Option<SemTyKind<'ast>>) -> Self {
+        Self {
+            aliased_ty: aliased_ty.into(),
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SemAdtKind {
+    Struct,
+    Enum,
+    Union,
+}
+
+/// The semantic view of a struct, enum or union. Structs and unions are
+/// represented with a single variant.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemAdtItem<'ast> {
+    def_id: TyDefId,
+    adt_kind: SemAdtKind,
+    is_non_exhaustive: bool,
+    variants: FfiSlice<'ast, SemVariant<'ast>>,
+}
+
+impl<'ast> SemAdtItem<'ast> {
+    /// The [`TyDefId`] of this ADT, which is used by semantic types, like
+    /// [`SemAdtTy`](crate::ast::ty::SemAdtTy).
+    pub fn def_id(&self) -> TyDefId {
+        self.def_id
+    }
+
+    pub fn adt_kind(&self) -> SemAdtKind {
+        self.adt_kind
+    }
+
+    /// Returns `true`, if this ADT is marked as `#[non_exhaustive]`. For enums, this
+    /// means that new variants can be added, for structs and unions, that new fields
+    /// can be added. Enum variants can be checked with [`SemVariant::is_non_exhaustive`].
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.is_non_exhaustive
+    }
+
+    pub fn variants(&self) -> &'ast [SemVariant<'ast>] {
+        self.variants.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemAdtItem<'ast> {
+    pub fn new(
+        def_id: TyDefId,
+        adt_kind: SemAdtKind,
+        is_non_exhaustive: bool,
+        variants: &'ast [SemVariant<'ast>],
+    ) -> Self {
+        Self {
+            def_id,
+            adt_kind,
+            is_non_exhaustive,
+            variants: variants.into(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemVariant<'ast> {
+    name: SymbolId,
+    is_non_exhaustive: bool,
+    fields: FfiSlice<'ast, SemField<'ast>>,
+}
+
+impl<'ast> SemVariant<'ast> {
+    pub fn name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    /// Returns `true`, if the fields of this variant are marked as `#[non_exhaustive]`.
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.is_non_exhaustive
+    }
+
+    pub fn fields(&self) -> &'ast [SemField<'ast>] {
+        self.fields.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemVariant<'ast> {
+    pub fn new(name: SymbolId, is_non_exhaustive: bool, fields: &'ast [SemField<'ast>]) -> Self {
+        Self {
+            name,
+            is_non_exhaustive,
+            fields: fields.into(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemField<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    name: SymbolId,
+    visibility: VisibilityKind,
+    ty: SemTyKind<'ast>,
+}
+
+impl<'ast> SemField<'ast> {
+    /// The name of the field. Fields of tuple structs and variants use their
+    /// index as a name.
+    pub fn name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    pub fn visibility(&self) -> VisibilityKind {
+        self.visibility
+    }
+
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        self.ty
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemField<'ast> {
+    pub fn new(name: SymbolId, visibility: VisibilityKind, ty: SemTyKind<'ast>) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            name,
+            visibility,
+            ty,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemTraitItem<'ast> {
+    def_id: TyDefId,
+    safety: Safety,
+    items: FfiSlice<'ast, ItemId>,
+}
+
+impl<'ast> SemTraitItem<'ast> {
+    /// The [`TyDefId`] of this trait, which is used by semantic types and
+    /// trait bounds.
+    pub fn def_id(&self) -> TyDefId {
+        self.def_id
+    }
+
+    pub fn safety(&self) -> Safety {
+        self.safety
+    }
+
+    /// The associated items of this trait. Their semantic view can be requested
+    /// via [`AstContext::sem_item`](crate::context::AstContext::sem_item).
+    pub fn items(&self) -> &'ast [ItemId] {
+        self.items.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemTraitItem<'ast> {
+    pub fn new(def_id: TyDefId, safety: Safety, items: &'ast [ItemId]) -> Self {
+        Self {
+            def_id,
+            safety,
+            items: items.into(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemImplItem<'ast> {
+    self_ty: SemTyKind<'ast>,
+    trait_id: FfiOption<TyDefId>,
+    items: FfiSlice<'ast, ItemId>,
+}
+
+impl<'ast> SemImplItem<'ast> {
+    pub fn self_ty(&self) -> SemTyKind<'ast> {
+        self.self_ty
+    }
+
+    /// The [`TyDefId`] of the implemented trait. This will be `None` for inherent
+    /// implementations.
+    pub fn trait_id(&self) -> Option<TyDefId> {
+        self.trait_id.copy()
+    }
+
+    /// The associated items of this `impl` block. Their semantic view can be
+    /// requested via [`AstContext::sem_item`](crate::context::AstContext::sem_item).
+    pub fn items(&self) -> &'ast [ItemId] {
+        self.items.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemImplItem<'ast> {
+    pub fn new(self_ty: SemTyKind<'ast>, trait_id: Option<TyDefId>, items: &'ast [ItemId]) -> Self {
+        Self {
+            self_ty,
+            trait_id: trait_id.into(),
+            items: items.into(),
+        }
+    }
+}
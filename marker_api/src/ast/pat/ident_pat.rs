@@ -1,5 +1,5 @@
 use crate::{
-    ast::{Mutability, SymbolId, VarId},
+    ast::{ty::SemTyKind, Mutability, SymbolId, VarId},
     context::with_cx,
     ffi::FfiOption,
 };
@@ -34,6 +34,12 @@ impl<'ast> IdentPat<'ast> {
         self.is_ref
     }
 
+    /// Returns the semantic type of the bound variable. For bindings by
+    /// reference, like `ref x`, this is the type of the reference.
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        with_cx(self, |cx| cx.var_ty(self.var))
+    }
+
     /// The pattern, if the variable originates from a binding to a pattern.
     /// ```
     /// # let expr = 10;
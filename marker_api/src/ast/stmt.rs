@@ -1,7 +1,12 @@
-use crate::{context::with_cx, ffi::FfiOption, CtorBlocker};
+use crate::{
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+    CtorBlocker,
+};
 
 use super::{
-    expr::ExprKind, item::ItemKind, pat::PatKind, ty::SynTyKind, LetStmtId, Span, SpanId, StmtId, StmtIdInner,
+    attr::Attribute, expr::ExprKind, item::ItemKind, pat::PatKind, ty::SynTyKind, LetStmtId, Span, SpanId, StmtId,
+    StmtIdInner,
 };
 
 #[repr(C)]
@@ -31,11 +36,13 @@ impl<'ast> StmtKind<'ast> {
     }
 
     /// Returns the attributes attached to this statement.
-    ///
-    /// Currently, it's only a placeholder until a proper representation is implemented.
-    /// rust-marker/marker#51 tracks the task of implementing this. You're welcome to
-    /// leave any comments in that issue.
-    pub fn attrs(&self) {}
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        match self {
+            StmtKind::Item(node, ..) => node.attrs(),
+            StmtKind::Let(node, ..) => node.attrs(),
+            StmtKind::Expr(node, ..) => node.attrs(),
+        }
+    }
 }
 
 #[repr(C)]
@@ -47,6 +54,7 @@ pub struct LetStmt<'ast> {
     ty: FfiOption<SynTyKind<'ast>>,
     init: FfiOption<ExprKind<'ast>>,
     els: FfiOption<ExprKind<'ast>>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 impl<'ast> LetStmt<'ast> {
@@ -77,6 +85,10 @@ impl<'ast> LetStmt<'ast> {
     pub fn els(&self) -> Option<ExprKind> {
         self.els.copy()
     }
+
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -88,6 +100,7 @@ impl<'ast> LetStmt<'ast> {
         ty: Option<SynTyKind<'ast>>,
         init: Option<ExprKind<'ast>>,
         els: Option<ExprKind<'ast>>,
+        attrs: &'ast [Attribute<'ast>],
     ) -> Self {
         Self {
             id,
@@ -96,6 +109,7 @@ impl<'ast> LetStmt<'ast> {
             ty: ty.into(),
             init: init.into(),
             els: els.into(),
+            attrs: attrs.into(),
         }
     }
 }
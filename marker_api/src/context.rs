@@ -6,13 +6,20 @@ use std::{cell::RefCell, mem::transmute};
 
 use crate::{
     ast::{
-        item::{Body, ItemKind},
+        attr::Attribute,
+        expr::ExprKind,
+        expr::MethodTarget,
+        generic::SemGenericArgKind,
+        item::{Body, FnItem, ItemKind, SemItem},
+        pat::IdentPat,
+        stmt::StmtKind,
         ty::SemTyKind,
-        BodyId, ExprId, ItemId, Span, SpanId, SymbolId, TyDefId,
+        BodyId, ConstValue, DefPath, DefPathId, ExpnInfo, ExprId, FileLocation, ItemId, NodeId, NodeKind, Span, SpanId,
+        SpanSrcId, StmtId, SymbolId, TyDefId, VarId, VariantId,
     },
     diagnostic::{Diagnostic, DiagnosticBuilder, EmissionNode},
     ffi,
-    lint::{Level, Lint, MacroReport},
+    lint::{Level, Lint},
 };
 
 thread_local! {
@@ -108,6 +115,13 @@ impl<'ast> AstContext<'ast> {
     pub fn new(driver: &'ast DriverCallbacks<'ast>) -> Self {
         Self { driver }
     }
+
+    /// Reports that the given lint crate panicked while checking a node. The
+    /// `node` describes the checked node, like "an item". This is used by the
+    /// adapter, to report panics with the diagnostics of the driver.
+    pub fn report_lint_crate_panic(&self, krate: &str, node: &str, span: Option<&Span<'ast>>) {
+        (self.driver.report_lint_crate_panic)(self.driver.driver_context, krate.into(), node.into(), span.into());
+    }
 }
 
 impl<'ast> AstContext<'ast> {
@@ -126,7 +140,7 @@ impl<'ast> AstContext<'ast> {
     ) where
         F: FnOnce(&mut DiagnosticBuilder<'ast>),
     {
-        if matches!(lint.report_in_macro, MacroReport::No) && span.is_from_macro() {
+        if !lint.report_in_macro.is_reportable_at(span) {
             return;
         }
         let node = node.into();
@@ -145,15 +159,140 @@ impl<'ast> AstContext<'ast> {
     /// return `None` in special cases depending on the used driver.
     ///
     /// #### Driver information
-    /// * Rustc's driver will always return a valid item.
+    /// * Rustc's driver returns `None` for items from dependencies and for associated items. These
+    ///   can be accessed via their parent item. Items from dependencies can be inspected with
+    ///   [`AstContext::sem_item`].
     pub fn item(&self, id: ItemId) -> Option<ItemKind<'ast>> {
         self.driver.call_item(id)
     }
 
+    /// This returns the [`SemItem`] belonging to the given id. The semantic view
+    /// is based on the type information of the compiler and is therefore also
+    /// available for items from dependencies. This can be used to inspect the API
+    /// of other crates:
+    ///
+    /// ```ignore
+    /// if let SemTyKind::Adt(adt) = expr.ty() {
+    ///     if let Some(item) = cx.sem_item(adt.def_id()) {
+    ///         if let SemItemKind::Adt(adt_item) = item.kind() {
+    ///             println!("is non-exhaustive: {}", adt_item.is_non_exhaustive());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// This returns `None`, if the id doesn't belong to an item, like the
+    /// [`VariantId`] of an enum variant.
+    pub fn sem_item(&self, id: impl Into<DefPathId>) -> Option<&SemItem<'ast>> {
+        (self.driver.sem_item)(self.driver.driver_context, id.into()).copy()
+    }
+
     pub fn body(&self, id: BodyId) -> &Body<'ast> {
         self.driver.call_body(id)
     }
 
+    /// Evaluates the constant with the given [`BodyId`]. The id can be retrieved
+    /// from [`ConstExpr::body_id`], like for array lengths, const generic arguments
+    /// and enum discriminants, or from [`ConstItem::body_id`]:
+    ///
+    /// ```ignore
+    /// if let ItemKind::Const(item) = item
+    ///     && let Some(body_id) = item.body_id()
+    ///     && let ConstValue::Int(value) = cx.eval_const(body_id)
+    /// {
+    ///     println!("`{}` has the value {:?}", item.ident().unwrap().name(), value.to_i128());
+    /// }
+    /// ```
+    ///
+    /// This returns [`ConstValue::TooComplex`], if the value can't be represented
+    /// by [`ConstValue`], if the constant depends on generic parameters or if the
+    /// body doesn't belong to a constant, like the bodies of functions and statics.
+    ///
+    /// [`ConstExpr::body_id`]: crate::ast::expr::ConstExpr::body_id
+    /// [`ConstItem::body_id`]: crate::ast::item::ConstItem::body_id
+    pub fn eval_const(&self, id: BodyId) -> &ConstValue<'ast> {
+        self.driver.call_eval_const(id)
+    }
+
+    /// This returns the [`ExprKind`] belonging to the given [`ExprId`]. Ids of
+    /// expressions are provided by the API, for example as the target of a
+    /// [`BreakExpr`](crate::ast::expr::BreakExpr) or by [`ExprKind::id`].
+    ///
+    /// This returns `None`, if the expression has no representation in the
+    /// AST, for example, because it was created by desugaring.
+    pub fn expr(&self, id: ExprId) -> Option<ExprKind<'ast>> {
+        self.driver.call_expr(id)
+    }
+
+    /// This returns the [`StmtKind`] belonging to the given [`StmtId`].
+    ///
+    /// This returns `None`, if the statement has no representation in the
+    /// AST, for example, because it was created by desugaring.
+    pub fn stmt(&self, id: StmtId) -> Option<StmtKind<'ast>> {
+        self.driver.call_stmt(id)
+    }
+
+    /// This returns the [`IdentPat`] which declares the variable with the given
+    /// [`VarId`]. This can be used to get from the usage of a local variable to
+    /// its declaration:
+    ///
+    /// ```ignore
+    /// if let ExprKind::Path(path) = expr {
+    ///     if let AstPathTarget::Var(var_id) = path.path().resolve() {
+    ///         let decl = cx.var(var_id).unwrap();
+    ///         println!("`{}` is declared at {:?}", decl.name(), decl.span());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// For variables, which are bound in several alternatives of an or-pattern,
+    /// this returns the binding of the first alternative. This returns `None`,
+    /// if the variable is not declared by a pattern, like parameters of
+    /// trait functions without a default implementation.
+    pub fn var(&self, id: VarId) -> Option<&IdentPat<'ast>> {
+        self.driver.call_var(id)
+    }
+
+    /// Returns the parent of the given node, or `None` if the node is a root item
+    /// of the crate. See [`AstContext::ancestors`] for more information.
+    pub fn parent(&self, node: impl Into<NodeId>) -> Option<NodeKind<'ast>> {
+        self.ancestors(node).next()
+    }
+
+    /// Returns an iterator over the ancestors of the given node, starting with
+    /// its parent and ending with the outermost item:
+    ///
+    /// ```ignore
+    /// // For the expression `1` in `fn foo() { let _x = 1; }` this prints:
+    /// // `Stmt`, `Expr` (block), `Body` and `Item` (fn)
+    /// for node in cx.ancestors(expr.id()) {
+    ///     println!("{node:?}");
+    /// }
+    /// ```
+    ///
+    /// Elements, which are not represented by a [`NodeKind`], like match arms,
+    /// are skipped. The iterator is empty for nodes from dependencies.
+    pub fn ancestors(&self, node: impl Into<NodeId>) -> impl Iterator<Item = NodeKind<'ast>> {
+        self.driver.call_ancestors(node.into()).iter().copied()
+    }
+
+    /// Returns the innermost item, which contains the given node.
+    pub fn enclosing_item(&self, node: impl Into<NodeId>) -> Option<ItemKind<'ast>> {
+        self.ancestors(node).find_map(|node| match node {
+            NodeKind::Item(item) => Some(item),
+            _ => None,
+        })
+    }
+
+    /// Returns the innermost function item, which contains the given node. This
+    /// includes associated functions. Closures are not function items.
+    pub fn enclosing_fn(&self, node: impl Into<NodeId>) -> Option<&'ast FnItem<'ast>> {
+        self.ancestors(node).find_map(|node| match node {
+            NodeKind::Item(ItemKind::Fn(func)) => Some(func),
+            _ => None,
+        })
+    }
+
     /// This function tries to resolve the given path to the corresponding [`TyDefId`].
     ///
     /// The slice might be empty if the path could not be resolved. This could be
@@ -178,6 +317,112 @@ impl<'ast> AstContext<'ast> {
     pub fn resolve_ty_ids(&self, path: &str) -> &[TyDefId] {
         (self.driver.resolve_ty_ids)(self.driver.driver_context, path.into()).get()
     }
+
+    /// This function tries to resolve the given path to the [`ItemId`]s of functions,
+    /// including associated functions, like `std::vec::Vec::new` and trait methods,
+    /// like `core::clone::Clone::clone`.
+    ///
+    /// The returned ids can be compared with the [`AstPathTarget`] of a path
+    /// expression or the [`MethodTarget`] of a method call. The same notes, as for
+    /// [`AstContext::resolve_ty_ids`] apply.
+    ///
+    /// ```ignore
+    /// // Check if the called function is `std::mem::forget`
+    /// if let ExprKind::Call(call) = expr
+    ///     && let ExprKind::Path(path) = call.operand()
+    ///     && let AstPathTarget::Item(id) = path.path().resolve()
+    ///     && cx.resolve_fn_ids("std::mem::forget").contains(&id)
+    /// {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// [`AstPathTarget`]: crate::ast::AstPathTarget
+    pub fn resolve_fn_ids(&self, path: &str) -> &[ItemId] {
+        (self.driver.resolve_fn_ids)(self.driver.driver_context, path.into()).get()
+    }
+
+    /// This function tries to resolve the given path to the [`ItemId`]s of
+    /// constants, including associated constants, like `core::u32::MAX`.
+    ///
+    /// See [`AstContext::resolve_fn_ids`] for more information.
+    pub fn resolve_const_ids(&self, path: &str) -> &[ItemId] {
+        (self.driver.resolve_const_ids)(self.driver.driver_context, path.into()).get()
+    }
+
+    /// This function tries to resolve the given path to the [`ItemId`]s of statics.
+    ///
+    /// See [`AstContext::resolve_fn_ids`] for more information.
+    pub fn resolve_static_ids(&self, path: &str) -> &[ItemId] {
+        (self.driver.resolve_static_ids)(self.driver.driver_context, path.into()).get()
+    }
+
+    /// This function tries to resolve the given path to the [`TyDefId`]s of traits,
+    /// like `core::clone::Clone`. Traits are identified by [`TyDefId`]s in semantic
+    /// types, bounds and [`AstContext::implements_trait`]. The [`ItemId`] of a
+    /// trait can be retrieved from its [`TraitItem`](crate::ast::item::TraitItem).
+    ///
+    /// See [`AstContext::resolve_ty_ids`] for more information.
+    pub fn resolve_trait_ids(&self, path: &str) -> &[TyDefId] {
+        (self.driver.resolve_trait_ids)(self.driver.driver_context, path.into()).get()
+    }
+
+    /// This function tries to resolve the given path to the [`VariantId`]s of
+    /// enum variants, like `core::option::Option::Some`.
+    ///
+    /// See [`AstContext::resolve_fn_ids`] for more information.
+    pub fn resolve_variant_ids(&self, path: &str) -> &[VariantId] {
+        (self.driver.resolve_variant_ids)(self.driver.driver_context, path.into()).get()
+    }
+
+    /// Returns the human-readable [`DefPath`] of the given definition, like
+    /// `my_crate::net::Client::connect`. This works for definitions of the current
+    /// crate and of dependencies.
+    ///
+    /// ```ignore
+    /// if let AstPathTarget::Item(id) = path.resolve() {
+    ///     let def_path = cx.def_path(id);
+    ///     println!("the path points to `{def_path}`");
+    /// }
+    /// ```
+    pub fn def_path(&self, id: impl Into<DefPathId>) -> &DefPath<'ast> {
+        (self.driver.def_path)(self.driver.driver_context, id.into())
+    }
+
+    /// This function checks if the given type implements the trait, identified by
+    /// the given [`TyDefId`]. The id can be retrieved via
+    /// [`AstContext::resolve_trait_ids`]. The `generic_args` are the generic arguments
+    /// of the trait, excluding the `Self` type. Omitted arguments are filled with the
+    /// default values of the trait's parameters.
+    ///
+    /// ```ignore
+    /// // Check if the type of an expression implements `Copy`
+    /// fn is_copy<'ast>(cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> bool {
+    ///     cx.resolve_trait_ids("core::marker::Copy")
+    ///         .iter()
+    ///         .any(|trait_id| cx.implements_trait(expr.ty(), *trait_id, &[]))
+    /// }
+    /// ```
+    ///
+    /// Types containing generic parameters are checked with the bounds of the item,
+    /// which declares these parameters.
+    ///
+    /// The function returns `false`, if the id doesn't belong to a trait, if the
+    /// generic arguments don't match the parameters of the trait or if the type
+    /// can't be checked by the driver.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver currently can't check closure, function pointer, trait object and alias
+    ///   types, or arrays with a length that depends on generic parameters. It returns `false` for
+    ///   these.
+    pub fn implements_trait(
+        &self,
+        ty: SemTyKind<'ast>,
+        trait_id: TyDefId,
+        generic_args: &[SemGenericArgKind<'ast>],
+    ) -> bool {
+        (self.driver.implements_trait)(self.driver.driver_context, ty, trait_id, generic_args.into())
+    }
 }
 
 impl<'ast> AstContext<'ast> {
@@ -185,23 +430,42 @@ impl<'ast> AstContext<'ast> {
         self.driver.call_expr_ty(expr)
     }
 
+    pub(crate) fn var_ty(&self, var: VarId) -> SemTyKind<'ast> {
+        self.driver.call_var_ty(var)
+    }
+
+    pub(crate) fn expr_attrs(&self, expr: ExprId) -> &'ast [Attribute<'ast>] {
+        self.driver.call_expr_attrs(expr)
+    }
+
     // FIXME: This function should probably be removed in favor of a better
     // system to deal with spans. See rust-marker/marker#175
     pub(crate) fn span_snipped(&self, span: &Span<'ast>) -> Option<String> {
         self.driver.call_span_snippet(span)
     }
 
+    pub(crate) fn is_exported(&self, id: ItemId) -> bool {
+        self.driver.call_is_exported(id)
+    }
+
     pub(crate) fn span(&self, span_id: SpanId) -> &'ast Span<'ast> {
         self.driver.call_span(span_id)
     }
 
+    pub(crate) fn span_file_location(&self, span: &Span<'ast>) -> Option<&'ast FileLocation<'ast>> {
+        self.driver.call_span_file_location(span)
+    }
+
+    pub(crate) fn span_expn_info(&self, src_id: SpanSrcId) -> Option<&'ast ExpnInfo<'ast>> {
+        self.driver.call_span_expn_info(src_id)
+    }
+
     pub(crate) fn symbol_str(&self, sym: SymbolId) -> &'ast str {
         self.driver.call_symbol_str(sym)
     }
 
-    #[allow(unused)] // Will be used later(or removed)
-    pub(crate) fn resolve_method_target(&self, expr: ExprId) -> ItemId {
-        self.driver.resolve_method_target(expr)
+    pub(crate) fn resolve_method_target(&self, expr: ExprId) -> &'ast MethodTarget<'ast> {
+        self.driver.call_resolve_method_target(expr)
     }
 }
 
@@ -233,19 +497,40 @@ struct DriverCallbacks<'ast> {
     // Lint emission and information
     pub lint_level_at: extern "C" fn(&'ast (), &'static Lint, EmissionNode) -> Level,
     pub emit_diag: for<'a> extern "C" fn(&'ast (), &'a Diagnostic<'a, 'ast>),
+    pub report_lint_crate_panic:
+        for<'a> extern "C" fn(&'ast (), ffi::FfiStr<'a>, ffi::FfiStr<'a>, ffi::FfiOption<&'a Span<'ast>>),
 
     // Public utility
     pub item: extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<ItemKind<'ast>>,
     pub body: extern "C" fn(&'ast (), id: BodyId) -> &'ast Body<'ast>,
+    pub eval_const: extern "C" fn(&'ast (), id: BodyId) -> &'ast ConstValue<'ast>,
+    pub expr: extern "C" fn(&'ast (), id: ExprId) -> ffi::FfiOption<ExprKind<'ast>>,
+    pub stmt: extern "C" fn(&'ast (), id: StmtId) -> ffi::FfiOption<StmtKind<'ast>>,
+    pub var: extern "C" fn(&'ast (), id: VarId) -> ffi::FfiOption<&'ast IdentPat<'ast>>,
+    pub sem_item: extern "C" fn(&'ast (), DefPathId) -> ffi::FfiOption<&'ast SemItem<'ast>>,
+    pub ancestors: extern "C" fn(&'ast (), NodeId) -> ffi::FfiSlice<'ast, NodeKind<'ast>>,
 
     pub resolve_ty_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId>,
+    pub resolve_fn_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId>,
+    pub resolve_const_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId>,
+    pub resolve_static_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId>,
+    pub resolve_trait_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId>,
+    pub resolve_variant_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, VariantId>,
+    pub def_path: extern "C" fn(&'ast (), DefPathId) -> &'ast DefPath<'ast>,
+    pub implements_trait:
+        for<'a> extern "C" fn(&'ast (), SemTyKind<'ast>, TyDefId, ffi::FfiSlice<'a, SemGenericArgKind<'ast>>) -> bool,
 
     // Internal utility
     pub expr_ty: extern "C" fn(&'ast (), ExprId) -> SemTyKind<'ast>,
+    pub var_ty: extern "C" fn(&'ast (), VarId) -> SemTyKind<'ast>,
+    pub expr_attrs: extern "C" fn(&'ast (), ExprId) -> ffi::FfiSlice<'ast, Attribute<'ast>>,
+    pub is_exported: extern "C" fn(&'ast (), ItemId) -> bool,
     pub span: extern "C" fn(&'ast (), SpanId) -> &'ast Span<'ast>,
     pub span_snippet: extern "C" fn(&'ast (), &Span<'ast>) -> ffi::FfiOption<ffi::FfiStr<'ast>>,
+    pub span_file_location: extern "C" fn(&'ast (), &Span<'ast>) -> ffi::FfiOption<&'ast FileLocation<'ast>>,
+    pub span_expn_info: extern "C" fn(&'ast (), SpanSrcId) -> ffi::FfiOption<&'ast ExpnInfo<'ast>>,
     pub symbol_str: extern "C" fn(&'ast (), SymbolId) -> ffi::FfiStr<'ast>,
-    pub resolve_method_target: extern "C" fn(&'ast (), ExprId) -> ItemId,
+    pub resolve_method_target: extern "C" fn(&'ast (), ExprId) -> &'ast MethodTarget<'ast>,
 }
 
 impl<'ast> DriverCallbacks<'ast> {
@@ -263,10 +548,34 @@ impl<'ast> DriverCallbacks<'ast> {
     fn call_body(&self, id: BodyId) -> &'ast Body<'ast> {
         (self.body)(self.driver_context, id)
     }
+    fn call_eval_const(&self, id: BodyId) -> &'ast ConstValue<'ast> {
+        (self.eval_const)(self.driver_context, id)
+    }
+    fn call_expr(&self, id: ExprId) -> Option<ExprKind<'ast>> {
+        (self.expr)(self.driver_context, id).copy()
+    }
+    fn call_stmt(&self, id: StmtId) -> Option<StmtKind<'ast>> {
+        (self.stmt)(self.driver_context, id).copy()
+    }
+    fn call_var(&self, id: VarId) -> Option<&'ast IdentPat<'ast>> {
+        (self.var)(self.driver_context, id).copy()
+    }
+    fn call_ancestors(&self, id: NodeId) -> &'ast [NodeKind<'ast>] {
+        (self.ancestors)(self.driver_context, id).get()
+    }
 
     fn call_expr_ty(&self, expr: ExprId) -> SemTyKind<'ast> {
         (self.expr_ty)(self.driver_context, expr)
     }
+    fn call_var_ty(&self, var: VarId) -> SemTyKind<'ast> {
+        (self.var_ty)(self.driver_context, var)
+    }
+    fn call_expr_attrs(&self, expr: ExprId) -> &'ast [Attribute<'ast>] {
+        (self.expr_attrs)(self.driver_context, expr).get()
+    }
+    fn call_is_exported(&self, id: ItemId) -> bool {
+        (self.is_exported)(self.driver_context, id)
+    }
     fn call_span(&self, span_id: SpanId) -> &'ast Span<'ast> {
         (self.span)(self.driver_context, span_id)
     }
@@ -274,10 +583,16 @@ impl<'ast> DriverCallbacks<'ast> {
         let result: Option<ffi::FfiStr> = (self.span_snippet)(self.driver_context, span).into();
         result.map(|x| x.to_string())
     }
+    fn call_span_file_location(&self, span: &Span<'ast>) -> Option<&'ast FileLocation<'ast>> {
+        (self.span_file_location)(self.driver_context, span).copy()
+    }
+    fn call_span_expn_info(&self, src_id: SpanSrcId) -> Option<&'ast ExpnInfo<'ast>> {
+        (self.span_expn_info)(self.driver_context, src_id).copy()
+    }
     fn call_symbol_str(&self, sym: SymbolId) -> &'ast str {
         (self.symbol_str)(self.driver_context, sym).get()
     }
-    pub fn resolve_method_target(&self, expr: ExprId) -> ItemId {
+    fn call_resolve_method_target(&self, expr: ExprId) -> &'ast MethodTarget<'ast> {
         (self.resolve_method_target)(self.driver_context, expr)
     }
 }
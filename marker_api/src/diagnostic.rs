@@ -2,7 +2,7 @@
 //! [`DiagnosticBuilder`] is the public stable interface, to construct messages.
 
 use crate::{
-    ast::{ExprId, FieldId, ItemId, Span, StmtId, VariantId},
+    ast::{CrateId, ExprId, FieldId, ItemId, Span, StmtId, VariantId},
     context::AstContext,
     ffi::{FfiSlice, FfiStr},
     lint::Lint,
@@ -16,7 +16,8 @@ pub struct DiagnosticBuilder<'ast> {
     msg: String,
     node: EmissionNode,
     span: Span<'ast>,
-    parts: Vec<DiagnosticPart<String, Span<'ast>>>,
+    additional_spans: Vec<Span<'ast>>,
+    parts: Vec<OwnedDiagnosticPart<'ast>>,
 }
 
 #[allow(clippy::needless_pass_by_value)] // `&impl ToString` doesn't work
@@ -27,10 +28,59 @@ impl<'ast> DiagnosticBuilder<'ast> {
             msg,
             node,
             span,
+            additional_spans: vec![],
             parts: vec![],
         }
     }
 
+    /// This function adds an additional primary [`Span`] to the diagnostic message.
+    /// All primary spans are highlighted as the main location of the diagnostic.
+    /// This is useful, if the issue is caused by the combination of several
+    /// code snippets, like two conflicting statements.
+    ///
+    /// From rustc a diagnostic with multiple primary spans would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:1:1
+    ///   |
+    /// 1 | first_expression
+    ///   | ^^^^^^^^^^^^^^^^
+    /// 2 | second_expression
+    ///   | ^^^^^^^^^^^^^^^^^            <-- The primary span added by this function
+    /// ```
+    ///
+    /// [`Self::span_label`] can be used to annotate the primary spans.
+    pub fn add_primary_span(&mut self, span: &Span<'ast>) -> &mut Self {
+        self.additional_spans.push(span.clone());
+        self
+    }
+
+    /// This function adds a label to the given [`Span`]. Labels are short
+    /// annotations, which are displayed directly under the span in the same
+    /// code snippet as the primary spans. Spans which are not a primary span of
+    /// the diagnostic, are displayed as secondary spans.
+    ///
+    /// From rustc a span label would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:1:1
+    ///   |
+    /// 1 | let x = expression;
+    ///   |     -   ^^^^^^^^^^
+    ///   |     |
+    ///   |     <text>                   <-- The label added by this function
+    /// ```
+    ///
+    /// [`Self::span_note`] can be used to display a span with a message in a
+    /// separate code snippet.
+    pub fn span_label(&mut self, span: &Span<'ast>, msg: impl ToString) -> &mut Self {
+        self.parts.push(DiagnosticPart::SpanLabel {
+            msg: msg.to_string(),
+            span: span.clone(),
+        });
+        self
+    }
+
     /// This function adds a note to the diagnostic message. Notes are intended
     /// to provide additional context or explanations about the diagnostic.
     ///
@@ -157,13 +207,78 @@ impl<'ast> DiagnosticBuilder<'ast> {
         });
     }
 
+    /// This function adds a spanned help message with a suggestion, which consists
+    /// of multiple parts. Each part replaces the code of the given [`Span`] with the
+    /// given string. All parts are applied together, this allows suggestions which
+    /// require several coordinated edits, like wrapping an expression in parentheses.
+    /// The confidence of the suggestion is expressed with the [`Applicability`].
+    ///
+    /// From rustc a multipart suggestion would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:1:1
+    ///   |
+    /// 1 | a + b * c
+    ///   | ^^^^^^^^^
+    ///   |
+    /// help: <msg>                    <--
+    ///   |                            <--
+    /// 1 | (a + b) * c                <-- The suggestion added by this function
+    ///   | ~~   ~~                    <--
+    /// ```
+    ///
+    /// The spans of the parts must not overlap. The suggestion is ignored, if no
+    /// parts are provided.
+    pub fn multipart_suggestion<'a>(
+        &mut self,
+        msg: impl ToString,
+        parts: impl IntoIterator<Item = (&'a Span<'ast>, impl ToString)>,
+        app: Applicability,
+    ) where
+        'ast: 'a,
+    {
+        let parts: Vec<_> = parts
+            .into_iter()
+            .map(|(span, sugg)| SuggestionPart {
+                span: span.clone(),
+                sugg: sugg.to_string(),
+            })
+            .collect();
+        if parts.is_empty() {
+            return;
+        }
+
+        self.parts.push(DiagnosticPart::MultipartSuggestion {
+            msg: msg.to_string(),
+            parts,
+            app,
+        });
+    }
+
     pub(crate) fn emit<'builder>(&'builder self, cx: &AstContext<'ast>) {
-        let parts: Vec<_> = self.parts.iter().map(DiagnosticPart::to_ffi_part).collect();
+        let suggestion_parts: Vec<Vec<_>> = self
+            .parts
+            .iter()
+            .map(|part| match part {
+                DiagnosticPart::MultipartSuggestion { parts, .. } => {
+                    parts.iter().map(SuggestionPart::to_ffi_part).collect()
+                },
+                _ => vec![],
+            })
+            .collect();
+        let parts: Vec<_> = self
+            .parts
+            .iter()
+            .zip(&suggestion_parts)
+            .map(|(part, suggestion_parts)| part.to_ffi_part(suggestion_parts))
+            .collect();
+        let additional_spans: Vec<_> = self.additional_spans.iter().collect();
         let diag = Diagnostic {
             lint: self.lint,
             msg: self.msg.as_str().into(),
             node: self.node,
             span: &self.span,
+            additional_spans: additional_spans.as_slice().into(),
             parts: parts.as_slice().into(),
         };
         cx.emit_diagnostic(&diag);
@@ -179,6 +294,8 @@ pub enum EmissionNode {
     Stmt(StmtId),
     Field(FieldId),
     Variant(VariantId),
+    /// The root of a crate. Only the currently linted crate is supported.
+    Crate(CrateId),
 }
 
 macro_rules! impl_into_emission_node_for {
@@ -202,12 +319,13 @@ impl_into_emission_node_for!(Item, ItemId);
 impl_into_emission_node_for!(Stmt, StmtId);
 impl_into_emission_node_for!(Field, FieldId);
 impl_into_emission_node_for!(Variant, VariantId);
+impl_into_emission_node_for!(Crate, CrateId);
 
 #[repr(C)]
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "driver-api", visibility::make(pub))]
-pub(crate) enum DiagnosticPart<St, Sp> {
+pub(crate) enum DiagnosticPart<St, Sp, Ps> {
     Help {
         msg: St,
     },
@@ -228,10 +346,26 @@ pub(crate) enum DiagnosticPart<St, Sp> {
         sugg: St,
         app: Applicability,
     },
+    MultipartSuggestion {
+        msg: St,
+        parts: Ps,
+        app: Applicability,
+    },
+    SpanLabel {
+        msg: St,
+        span: Sp,
+    },
 }
 
-impl<'ast> DiagnosticPart<String, Span<'ast>> {
-    fn to_ffi_part<'part>(&'part self) -> DiagnosticPart<FfiStr<'part>, &'part Span<'ast>> {
+type OwnedDiagnosticPart<'ast> = DiagnosticPart<String, Span<'ast>, Vec<SuggestionPart<String, Span<'ast>>>>;
+type FfiDiagnosticPart<'part, 'ast> =
+    DiagnosticPart<FfiStr<'part>, &'part Span<'ast>, FfiSlice<'part, SuggestionPart<FfiStr<'part>, &'part Span<'ast>>>>;
+
+impl<'ast> OwnedDiagnosticPart<'ast> {
+    fn to_ffi_part<'part>(
+        &'part self,
+        suggestion_parts: &'part [SuggestionPart<FfiStr<'part>, &'part Span<'ast>>],
+    ) -> FfiDiagnosticPart<'part, 'ast> {
         match self {
             DiagnosticPart::Help { msg } => DiagnosticPart::Help { msg: msg.into() },
             DiagnosticPart::HelpSpan { msg, span } => DiagnosticPart::HelpSpan { msg: msg.into(), span },
@@ -243,6 +377,30 @@ impl<'ast> DiagnosticPart<String, Span<'ast>> {
                 sugg: sugg.into(),
                 app: *app,
             },
+            DiagnosticPart::MultipartSuggestion { msg, app, .. } => DiagnosticPart::MultipartSuggestion {
+                msg: msg.into(),
+                parts: suggestion_parts.into(),
+                app: *app,
+            },
+            DiagnosticPart::SpanLabel { msg, span } => DiagnosticPart::SpanLabel { msg: msg.into(), span },
+        }
+    }
+}
+
+/// A single replacement of a [`DiagnosticPart::MultipartSuggestion`].
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct SuggestionPart<St, Sp> {
+    pub span: Sp,
+    pub sugg: St,
+}
+
+impl<'ast> SuggestionPart<String, Span<'ast>> {
+    fn to_ffi_part<'part>(&'part self) -> SuggestionPart<FfiStr<'part>, &'part Span<'ast>> {
+        SuggestionPart {
+            span: &self.span,
+            sugg: (&self.sugg).into(),
         }
     }
 }
@@ -288,7 +446,8 @@ pub(crate) struct Diagnostic<'builder, 'ast> {
     pub msg: FfiStr<'builder>,
     pub node: EmissionNode,
     pub span: &'builder Span<'ast>,
-    pub parts: FfiSlice<'builder, DiagnosticPart<FfiStr<'builder>, &'builder Span<'ast>>>,
+    pub additional_spans: FfiSlice<'builder, &'builder Span<'ast>>,
+    pub parts: FfiSlice<'builder, FfiDiagnosticPart<'builder, 'ast>>,
 }
 
 impl<'builder, 'ast> Diagnostic<'builder, 'ast> {
@@ -27,13 +27,13 @@ impl<'a> PartialEq for FfiStr<'a> {
     }
 }
 
+// `FfiStr` is an immutable view into a `&str`, which is `Send` and `Sync`
+unsafe impl Send for FfiStr<'_> {}
+unsafe impl Sync for FfiStr<'_> {}
+
 impl<'a> From<&'a str> for FfiStr<'a> {
     fn from(source: &'a str) -> Self {
-        Self {
-            _lifetime: PhantomData,
-            data: source.as_ptr(),
-            len: source.len(),
-        }
+        Self::new(source)
     }
 }
 
@@ -44,6 +44,16 @@ impl<'a> From<&'a String> for FfiStr<'a> {
 }
 
 impl<'a> FfiStr<'a> {
+    /// This is a `const` version of the `From<&str>` conversion, which allows
+    /// the creation in `static` items.
+    pub const fn new(source: &'a str) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            data: source.as_ptr(),
+            len: source.len(),
+        }
+    }
+
     pub fn get(&self) -> &'a str {
         unsafe {
             let data = slice::from_raw_parts(self.data, self.len);
@@ -129,7 +139,6 @@ impl<T> From<Option<T>> for FfiOption<T> {
 }
 
 #[repr(C)]
-#[derive(Clone, Copy)]
 pub struct FfiSlice<'a, T> {
     _lifetime: PhantomData<&'a ()>,
     /// Not really *const, but it should have the lifetime of at least `'a`
@@ -137,6 +146,16 @@ pub struct FfiSlice<'a, T> {
     len: usize,
 }
 
+// The slice only references the data, it can therefore be copied, regardless
+// of `T`. A derive would require `T: Copy`.
+impl<'a, T> Clone for FfiSlice<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for FfiSlice<'a, T> {}
+
 impl<'a, T: Eq> Eq for FfiSlice<'a, T> {}
 
 impl<'a, T: PartialEq> PartialEq for FfiSlice<'a, T> {
@@ -1,7 +1,11 @@
 //! A module responsible for generating and exposing an interface from lint crates.
 //! [`export_lint_pass`](crate::export_lint_pass) is the main macro, from this module.
 
-use crate::{context::AstContext, ffi::FfiSlice, lint::Lint};
+use crate::{
+    context::AstContext,
+    ffi::{FfiOption, FfiSlice, FfiStr},
+    lint::Lint,
+};
 
 /// **!Unstable!**
 /// This struct is used to connect lint crates to drivers.
@@ -11,15 +15,29 @@ pub struct LintCrateBindings {
     pub set_ast_context: for<'ast> extern "C" fn(cx: &'ast AstContext<'ast>),
 
     // lint pass functions
+    //
+    // The `check_*` functions return `false`, if the lint pass panicked.
     pub info: for<'ast> extern "C" fn() -> LintPassInfo,
-    pub check_item: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::item::ItemKind<'ast>),
-    pub check_field: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::Field<'ast>),
-    pub check_variant: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::EnumVariant<'ast>),
-    pub check_body: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::Body<'ast>),
-    pub check_stmt: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::stmt::StmtKind<'ast>),
-    pub check_expr: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::expr::ExprKind<'ast>),
+    pub check_crate: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::Crate<'ast>) -> bool,
+    pub check_crate_post: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::Crate<'ast>) -> bool,
+    pub check_item: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::item::ItemKind<'ast>) -> bool,
+    pub check_item_post: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::item::ItemKind<'ast>) -> bool,
+    pub check_field: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::Field<'ast>) -> bool,
+    pub check_variant:
+        for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::EnumVariant<'ast>) -> bool,
+    pub check_body: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::Body<'ast>) -> bool,
+    pub check_body_post: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::Body<'ast>) -> bool,
+    pub check_stmt: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::stmt::StmtKind<'ast>) -> bool,
+    pub check_stmt_post: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::stmt::StmtKind<'ast>) -> bool,
+    pub check_expr: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::expr::ExprKind<'ast>) -> bool,
+    pub check_expr_post: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::expr::ExprKind<'ast>) -> bool,
+    pub check_pat: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::pat::PatKind<'ast>) -> bool,
+    pub check_ty: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::ty::SynTyKind<'ast>) -> bool,
 }
 
+mod abi_fingerprint;
+pub(crate) use abi_fingerprint::ABI_FINGERPRINT;
+
 /// This macro marks the given struct as the main [`LintPass`](`crate::LintPass`)
 /// for the lint crate. For structs implementing [`Default`] it's enough to only
 /// pass in the type. Otherwise, a second argument is required to initialize an
@@ -66,6 +84,11 @@ macro_rules! export_lint_pass {
                 $crate::MARKER_API_VERSION
             }
 
+            #[no_mangle]
+            extern "C" fn marker_api_abi_fingerprint() -> u64 {
+                $crate::MARKER_API_ABI_FINGERPRINT
+            }
+
             /// This magic function fills the `LintCrateBindings` struct to allow easy
             /// communication between marker's driver and lint crates.
             #[no_mangle]
@@ -75,55 +98,119 @@ macro_rules! export_lint_pass {
                 extern "C" fn set_ast_context<'ast>(cx: &'ast $crate::AstContext<'ast>) {
                     $crate::context::set_ast_cx(cx);
                 }
+                /// Panics can't unwind across the FFI boundary, as each lint crate
+                /// has its own copy of `std`. They are therefore caught here and
+                /// reported to the driver via the return value of the `check_*`
+                /// functions.
+                fn catch_panic(check: impl FnOnce()) -> bool {
+                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(check)).is_ok()
+                }
+
                 extern "C" fn info() -> $crate::LintPassInfo {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().info())
                 }
+                extern "C" fn check_crate<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    krate: &'ast $crate::ast::Crate<'ast>,
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_crate(cx, krate)))
+                }
+                extern "C" fn check_crate_post<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    krate: &'ast $crate::ast::Crate<'ast>,
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_crate_post(cx, krate)))
+                }
                 extern "C" fn check_item<'ast>(
                     cx: &'ast $crate::AstContext<'ast>,
                     item: $crate::ast::item::ItemKind<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_item(cx, item));
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_item(cx, item)))
+                }
+                extern "C" fn check_item_post<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    item: $crate::ast::item::ItemKind<'ast>,
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_item_post(cx, item)))
                 }
                 extern "C" fn check_field<'ast>(
                     cx: &'ast $crate::AstContext<'ast>,
                     field: &'ast $crate::ast::item::Field<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_field(cx, field));
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_field(cx, field)))
                 }
                 extern "C" fn check_variant<'ast>(
                     cx: &'ast $crate::AstContext<'ast>,
                     variant: &'ast $crate::ast::item::EnumVariant<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_variant(cx, variant));
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_variant(cx, variant)))
                 }
                 extern "C" fn check_body<'ast>(
                     cx: &'ast $crate::AstContext<'ast>,
                     body: &'ast $crate::ast::item::Body<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_body(cx, body));
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_body(cx, body)))
+                }
+                extern "C" fn check_body_post<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    body: &'ast $crate::ast::item::Body<'ast>,
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_body_post(cx, body)))
                 }
                 extern "C" fn check_stmt<'ast>(
                     cx: &'ast $crate::AstContext<'ast>,
                     stmt: $crate::ast::stmt::StmtKind<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_stmt(cx, stmt));
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_stmt(cx, stmt)))
+                }
+                extern "C" fn check_stmt_post<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    stmt: $crate::ast::stmt::StmtKind<'ast>,
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_stmt_post(cx, stmt)))
                 }
                 extern "C" fn check_expr<'ast>(
                     cx: &'ast $crate::AstContext<'ast>,
                     expr: $crate::ast::expr::ExprKind<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_expr(cx, expr));
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_expr(cx, expr)))
+                }
+                extern "C" fn check_expr_post<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    expr: $crate::ast::expr::ExprKind<'ast>,
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_expr_post(cx, expr)))
+                }
+                extern "C" fn check_pat<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    pat: $crate::ast::pat::PatKind<'ast>,
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_pat(cx, pat)))
+                }
+                extern "C" fn check_ty<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    ty: $crate::ast::ty::SynTyKind<'ast>,
+                ) -> bool {
+                    catch_panic(|| super::__MARKER_STATE.with(|state| state.borrow_mut().check_ty(cx, ty)))
                 }
 
                 $crate::interface::LintCrateBindings {
                     set_ast_context,
                     info,
+                    check_crate,
+                    check_crate_post,
                     check_item,
+                    check_item_post,
                     check_field,
                     check_variant,
                     check_body,
+                    check_body_post,
                     check_stmt,
+                    check_stmt_post,
                     check_expr,
+                    check_expr_post,
+                    check_pat,
+                    check_ty,
                 }
             }
         }
@@ -133,6 +220,12 @@ macro_rules! export_lint_pass {
 #[derive(Debug)]
 pub struct LintPassInfoBuilder {
     lints: &'static [&'static Lint],
+    crate_name: Option<&'static str>,
+    version: Option<&'static str>,
+    description: Option<&'static str>,
+    homepage: Option<&'static str>,
+    repository: Option<&'static str>,
+    required_api_features: &'static [FfiStr<'static>],
 }
 
 impl LintPassInfoBuilder {
@@ -146,13 +239,83 @@ impl LintPassInfoBuilder {
             // is called. Ideally, it would be cool to just store the `Box` directly but
             // that is sadly not possible due to ABI constraints
             lints: Box::leak(lints),
+            crate_name: None,
+            version: None,
+            description: None,
+            homepage: None,
+            repository: None,
+            required_api_features: &[],
         }
     }
 
+    /// Sets the name of the lint crate. The name is used by drivers and
+    /// `cargo-marker` to identify the lint crate in their output. It defaults
+    /// to the file name of the compiled library.
+    ///
+    /// Most lint crates can use the package information provided by Cargo:
+    ///
+    /// ```
+    /// # use marker_api::LintPassInfoBuilder;
+    /// # let builder = LintPassInfoBuilder::new(Box::new([]));
+    /// builder
+    ///     .crate_name(env!("CARGO_PKG_NAME"))
+    ///     .version(env!("CARGO_PKG_VERSION"))
+    ///     .description(env!("CARGO_PKG_DESCRIPTION"))
+    ///     .repository(env!("CARGO_PKG_REPOSITORY"))
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn crate_name(mut self, name: &'static str) -> Self {
+        self.crate_name = Some(name);
+        self
+    }
+
+    /// Sets the version of the lint crate.
+    #[must_use]
+    pub fn version(mut self, version: &'static str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets a short description of the lint crate.
+    #[must_use]
+    pub fn description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the URL of the lint crate's homepage.
+    #[must_use]
+    pub fn homepage(mut self, homepage: &'static str) -> Self {
+        self.homepage = Some(homepage);
+        self
+    }
+
+    /// Sets the URL of the lint crate's source repository.
+    #[must_use]
+    pub fn repository(mut self, repository: &'static str) -> Self {
+        self.repository = Some(repository);
+        self
+    }
+
+    /// Sets the features of `marker_api`, which are required by the lint crate.
+    #[must_use]
+    pub fn required_api_features(mut self, features: &[&'static str]) -> Self {
+        let features: Box<[FfiStr<'static>]> = features.iter().map(|feature| (*feature).into()).collect();
+        self.required_api_features = Box::leak(features);
+        self
+    }
+
     /// This method builds the [`LintPassInfo`], ready for consumption.
     pub fn build(self) -> LintPassInfo {
         LintPassInfo {
             lints: self.lints.into(),
+            crate_name: self.crate_name.map(Into::into).into(),
+            version: self.version.map(Into::into).into(),
+            description: self.description.map(Into::into).into(),
+            homepage: self.homepage.map(Into::into).into(),
+            repository: self.repository.map(Into::into).into(),
+            required_api_features: self.required_api_features.into(),
         }
     }
 }
@@ -168,11 +331,41 @@ impl LintPassInfoBuilder {
 #[non_exhaustive]
 pub struct LintPassInfo {
     lints: FfiSlice<'static, &'static Lint>,
+    crate_name: FfiOption<FfiStr<'static>>,
+    version: FfiOption<FfiStr<'static>>,
+    description: FfiOption<FfiStr<'static>>,
+    homepage: FfiOption<FfiStr<'static>>,
+    repository: FfiOption<FfiStr<'static>>,
+    required_api_features: FfiSlice<'static, FfiStr<'static>>,
 }
 
 #[cfg(feature = "driver-api")]
 impl LintPassInfo {
-    pub fn lints(&self) -> &[&'static Lint] {
+    pub fn lints(&self) -> &'static [&'static Lint] {
         self.lints.get()
     }
+
+    pub fn crate_name(&self) -> Option<&'static str> {
+        self.crate_name.get().map(FfiStr::get)
+    }
+
+    pub fn version(&self) -> Option<&'static str> {
+        self.version.get().map(FfiStr::get)
+    }
+
+    pub fn description(&self) -> Option<&'static str> {
+        self.description.get().map(FfiStr::get)
+    }
+
+    pub fn homepage(&self) -> Option<&'static str> {
+        self.homepage.get().map(FfiStr::get)
+    }
+
+    pub fn repository(&self) -> Option<&'static str> {
+        self.repository.get().map(FfiStr::get)
+    }
+
+    pub fn required_api_features(&self) -> impl Iterator<Item = &'static str> {
+        self.required_api_features.get().iter().map(FfiStr::get)
+    }
 }
@@ -0,0 +1,6 @@
+// This file is generated by the `abi_fingerprint` test of `marker_api`. The
+// fingerprint has to be updated, when the definitions of shared types change:
+//
+// BLESS=1 cargo test -p marker_api --test abi_fingerprint
+
+pub(crate) const ABI_FINGERPRINT: u64 = 0x9850_e906_b862_6ccc;
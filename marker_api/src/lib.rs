@@ -11,6 +11,14 @@
 
 pub static MARKER_API_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// A fingerprint of the definitions of types, which are shared between drivers
+/// and lint crates. It's computed from the source code and checked by the
+/// `abi_fingerprint` test. It's used to verify that lint crates compiled with a
+/// different, but semver compatible, version of `marker_api` can be loaded by
+/// the driver.
+#[doc(hidden)]
+pub static MARKER_API_ABI_FINGERPRINT: u64 = interface::ABI_FINGERPRINT;
+
 pub mod ast;
 pub mod context;
 pub mod diagnostic;
@@ -28,15 +36,37 @@ pub use interface::{LintPassInfo, LintPassInfoBuilder};
 /// [`LintPass`] provides some additional information about the implemented lints.
 /// The adapter will walk through the entire AST once and give each node to the
 /// registered [`LintPass`]es.
+///
+/// The `check_*_post` functions are called after all child nodes of the given
+/// node have been visited. They can be used to maintain state for the current
+/// scope, like the nesting depth of `unsafe` blocks.
+///
+/// Lint passes, which have all their lints allowed at an item, are not called
+/// for the item and its child nodes. [`LintPass::check_crate`] and
+/// [`LintPass::check_crate_post`] are always called, to allow lint passes to
+/// initialize and finalize their state.
 pub trait LintPass {
     fn info(&self) -> LintPassInfo;
 
+    /// This function is called once for every crate, before any other nodes are
+    /// visited.
+    fn check_crate<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _krate: &'ast ast::Crate<'ast>) {}
+    /// This function is called once for every crate, after all nodes have been
+    /// visited. It can be used to emit lints based on information collected
+    /// during the traversal.
+    fn check_crate_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _krate: &'ast ast::Crate<'ast>) {}
     fn check_item<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _item: ast::item::ItemKind<'ast>) {}
+    fn check_item_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _item: ast::item::ItemKind<'ast>) {}
     fn check_field<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _field: &'ast ast::item::Field<'ast>) {}
     fn check_variant<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _variant: &'ast ast::item::EnumVariant<'ast>) {}
     fn check_body<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _body: &'ast ast::item::Body<'ast>) {}
+    fn check_body_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _body: &'ast ast::item::Body<'ast>) {}
     fn check_stmt<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _stmt: ast::stmt::StmtKind<'ast>) {}
+    fn check_stmt_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _stmt: ast::stmt::StmtKind<'ast>) {}
     fn check_expr<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _expr: ast::expr::ExprKind<'ast>) {}
+    fn check_expr_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _expr: ast::expr::ExprKind<'ast>) {}
+    fn check_pat<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _pat: ast::pat::PatKind<'ast>) {}
+    fn check_ty<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _ty: ast::ty::SynTyKind<'ast>) {}
 }
 
 pub(crate) mod private {
@@ -1,3 +1,8 @@
+use crate::{
+    ast::{ExpnInfo, Span},
+    ffi::FfiStr,
+};
+
 #[repr(C)]
 #[derive(Debug, PartialEq, Eq, Hash)]
 // This sadly cannot be marked as #[non_exhaustive] as the struct construction
@@ -14,6 +19,11 @@ pub struct Lint {
     /// The name is written with underscores, e.g., "unused_imports".
     /// On the command line, underscores become dashes.
     ///
+    /// Lints are namespaced by the lint crate, which declares them. The full
+    /// name has the form `marker::<lint_crate>::<lint_name>`, for example
+    /// `marker::my_lints::unused_imports`. This allows different lint crates
+    /// to declare lints with the same name.
+    ///
     /// See <https://rustc-dev-guide.rust-lang.org/diagnostics.html#lint-naming>
     /// for naming guidelines.
     ///
@@ -44,11 +54,14 @@ pub struct Lint {
     // * pub crate_level_only: bool,
 }
 
-/// FIXME(xFrednet): These settings should be working now, but are still limited
-/// due to the limited [`Span`](crate::ast::Span) implementation. Ideally, I would
-/// also like more options, like a `Local` variant that only lints in local marcos.
-/// For libraries it might also be cool to have a `Crate` variant, that only lints
-/// in user code and code from macros from the specified crate.
+/// This setting defines, if a lint is reported in code, that originates from
+/// macro expansions. Code written directly in the source files is always
+/// reported. The setting is enforced by
+/// [`AstContext::emit_lint`](crate::context::AstContext::emit_lint) using the
+/// macro backtrace of the span, that the lint is emitted at.
+///
+/// Desugarings, like `for` loops or the `?` operator, are not counted as macro
+/// expansions.
 ///
 /// See: rust-marker/marker#149
 #[repr(C)]
@@ -59,6 +72,133 @@ pub enum MacroReport {
     No,
     /// Report in local and external macros.
     All,
+    /// Report in macros, that are defined in the linted crate. Expansions of
+    /// external macros, like `vec![]`, are not reported. A span is only reported,
+    /// if all macros in its backtrace are local.
+    Local,
+    /// Report in macros, that are defined in the crate with the given name.
+    /// This is useful for libraries, which want to check the usage of their own
+    /// macros. A span is only reported, if all macros in its backtrace come from
+    /// the named crate. Dashes in the name are treated like underscores.
+    ///
+    /// This variant should be created with [`MacroReport::for_crate`]:
+    ///
+    /// ```ignore
+    /// marker_api::declare_lint! {
+    ///     /// # What it does
+    ///     /// ...
+    ///     FRAMEWORK_LINT,
+    ///     Warn,
+    ///     marker_api::lint::MacroReport::for_crate("my_framework"),
+    /// }
+    /// ```
+    Crate(FfiStr<'static>),
+}
+
+impl MacroReport {
+    /// Creates a [`MacroReport::Crate`] setting for the crate with the given
+    /// name. This can be used in `static` items, like the ones created by
+    /// [`declare_lint!`](crate::declare_lint).
+    pub const fn for_crate(name: &'static str) -> Self {
+        MacroReport::Crate(FfiStr::new(name))
+    }
+
+    /// Returns `true`, if a lint with this setting should be reported at the
+    /// given span.
+    pub fn is_reportable_at(&self, span: &Span<'_>) -> bool {
+        match self {
+            MacroReport::No => !span.is_from_macro(),
+            MacroReport::All => true,
+            MacroReport::Local => span
+                .macro_backtrace()
+                .filter(|info| info.is_macro())
+                .all(ExpnInfo::is_from_local_crate),
+            MacroReport::Crate(name) => span.macro_backtrace().filter(|info| info.is_macro()).all(|info| {
+                info.def_crate_name()
+                    .map_or(false, |krate| is_crate_name(krate, name.get()))
+            }),
+        }
+    }
+}
+
+/// Compares the crate names, while treating dashes like underscores. This
+/// avoids normalizing the name for every checked expansion.
+fn is_crate_name(krate: &str, name: &str) -> bool {
+    krate.len() == name.len()
+        && krate
+            .bytes()
+            .zip(name.bytes())
+            .all(|(a, b)| a == b || (a == b'_' && b == b'-'))
+}
+
+const LINT_NAME_PREFIX: &[u8] = b"marker::";
+
+/// Returns the length of the crate name, which is the first segment of the
+/// given module path.
+const fn crate_name_len(module_path: &str) -> usize {
+    let bytes = module_path.as_bytes();
+    let mut len = 0;
+    while len < bytes.len() && bytes[len] != b':' {
+        len += 1;
+    }
+    len
+}
+
+/// Returns the length of the lint name created by [`lint_name`].
+#[doc(hidden)]
+pub const fn lint_name_len(module_path: &str, lint: &str) -> usize {
+    LINT_NAME_PREFIX.len() + crate_name_len(module_path) + "::".len() + lint.len()
+}
+
+/// Creates the full name of a lint, in the form `marker::<lint_crate>::<lint_name>`.
+/// The crate name is taken from the given module path, which is always available,
+/// unlike environment values set by Cargo. This is used by [`declare_lint!`](crate::declare_lint).
+///
+/// `N` has to be the length returned by [`lint_name_len`].
+#[doc(hidden)]
+pub const fn lint_name<const N: usize>(module_path: &str, lint: &str) -> [u8; N] {
+    let mut name = [0; N];
+    let mut len = 0;
+
+    let mut index = 0;
+    while index < LINT_NAME_PREFIX.len() {
+        name[len] = LINT_NAME_PREFIX[index];
+        len += 1;
+        index += 1;
+    }
+
+    let crate_name_len = crate_name_len(module_path);
+    let module_path = module_path.as_bytes();
+    index = 0;
+    while index < crate_name_len {
+        name[len] = module_path[index];
+        len += 1;
+        index += 1;
+    }
+
+    name[len] = b':';
+    name[len + 1] = b':';
+    len += 2;
+
+    let lint = lint.as_bytes();
+    index = 0;
+    while index < lint.len() {
+        name[len] = lint[index];
+        len += 1;
+        index += 1;
+    }
+
+    assert!(len == N, "the length of the lint name doesn't match `N`");
+    name
+}
+
+/// Converts a lint name created by [`lint_name`] into a string.
+#[doc(hidden)]
+pub const fn lint_name_str(name: &'static [u8]) -> &'static str {
+    match std::str::from_utf8(name) {
+        Ok(name) => name,
+        Err(_) => panic!("lint names are always valid UTF-8"),
+    }
 }
 
 /// Setting for how to handle a lint.
@@ -95,6 +235,10 @@ pub enum Level {
 /// available in the crate documentation and any documentation generated by Marker.
 /// The content will be rendered with Markdown.
 ///
+/// The lint name is prefixed with `marker::` and the name of the lint crate.
+/// A lint `ITEM_WITH_TEST_NAME`, declared in a crate called `my_lints`, can be
+/// allowed with `#[allow(marker::my_lints::item_with_test_name)]`.
+///
 /// It's recommended to include a section explaining what the lint does, and an
 /// example. You can also provide additional information, like "limitations" or
 /// "known problems". Here is a recommended template:
@@ -139,7 +283,11 @@ macro_rules! declare_lint {
     ) => {
         $(#[doc = $doc])+
         pub static $NAME: &$crate::lint::Lint = &$crate::lint::Lint {
-            name: concat!("marker::", stringify!($NAME)),
+            name: {
+                const LEN: usize = $crate::lint::lint_name_len(module_path!(), stringify!($NAME));
+                const NAME: [u8; LEN] = $crate::lint::lint_name(module_path!(), stringify!($NAME));
+                $crate::lint::lint_name_str(&NAME)
+            },
             default_level: $crate::lint::Level::$LEVEL,
             explanation: concat!($($doc, '\n',)*),
             report_in_macro: $REPORT_IN_MACRO,
@@ -0,0 +1,240 @@
+//! This test computes the ABI fingerprint of `marker_api` and checks that it
+//! matches the checked-in value in `src/interface/abi_fingerprint.rs`. The
+//! fingerprint is a hash over the definitions of all types, which are shared
+//! between drivers and lint crates. Lint crates are only loaded by drivers
+//! with the same fingerprint.
+//!
+//! The hashed schema consists of:
+//! * All structs, enums and unions with a `#[repr(...)]` attribute. This includes
+//!   `LintCrateBindings` and `DriverCallbacks`, which define the functions called across the
+//!   library boundary.
+//! * All `macro_rules!` definitions, which contain `#[repr(...)]` types or `extern` functions, like
+//!   `new_id!` and `export_lint_pass!`.
+//! * All item-level macro invocations, since they can generate shared types.
+//!
+//! Documentation, comments, formatting and visibility don't affect the layout
+//! and are removed before hashing. Changes to them therefore keep the fingerprint.
+//!
+//! Run the test with `BLESS=1` to update the checked-in fingerprint.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::ToTokens;
+
+const FINGERPRINT_FILE: &str = "src/interface/abi_fingerprint.rs";
+
+#[test]
+fn abi_fingerprint_is_up_to_date() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut files = vec![];
+    collect_rust_files(&manifest_dir.join("src"), &mut files);
+
+    let mut schema = vec![];
+    for file in files {
+        let content = fs::read_to_string(&file).unwrap();
+        let parsed =
+            syn::parse_file(&content).unwrap_or_else(|err| panic!("failed to parse `{}`: {err}", file.display()));
+        collect_schema_items(parsed.items, &mut schema);
+    }
+    // Sorting the items ensures that moving items keeps the fingerprint
+    schema.sort_unstable();
+
+    let expected = fingerprint_file(fnv1a(schema.join("\n").as_bytes()));
+    let path = manifest_dir.join(FINGERPRINT_FILE);
+    let actual = fs::read_to_string(&path).unwrap();
+    if actual == expected {
+        return;
+    }
+
+    if env::var_os("BLESS").is_some() {
+        fs::write(&path, expected).unwrap();
+    } else {
+        panic!(
+            "the definitions of shared types have changed, the ABI fingerprint in `{FINGERPRINT_FILE}` \
+            has to be updated. Run `BLESS=1 cargo test -p marker_api --test abi_fingerprint` to update it."
+        );
+    }
+}
+
+fn fingerprint_file(fingerprint: u64) -> String {
+    let groups: Vec<_> = (0..4)
+        .rev()
+        .map(|group| format!("{:04x}", (fingerprint >> (group * 16)) & 0xffff))
+        .collect();
+    format!(
+        "\
+// This file is generated by the `abi_fingerprint` test of `marker_api`. The
+// fingerprint has to be updated, when the definitions of shared types change:
+//
+// BLESS=1 cargo test -p marker_api --test abi_fingerprint
+
+pub(crate) const ABI_FINGERPRINT: u64 = 0x{};
+",
+        groups.join("_")
+    )
+}
+
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_rust_files(&path, files);
+        } else if path.extension().map_or(false, |ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+fn collect_schema_items(items: Vec<syn::Item>, schema: &mut Vec<String>) {
+    for item in items {
+        let tokens = match item {
+            syn::Item::Mod(module) => {
+                if let Some((_, items)) = module.content {
+                    collect_schema_items(items, schema);
+                }
+                continue;
+            },
+            syn::Item::Struct(mut item) if has_repr(&item.attrs) => {
+                item.attrs.retain(is_repr);
+                item.vis = syn::Visibility::Inherited;
+                strip_fields(&mut item.fields);
+                item.into_token_stream()
+            },
+            syn::Item::Enum(mut item) if has_repr(&item.attrs) => {
+                item.attrs.retain(is_repr);
+                item.vis = syn::Visibility::Inherited;
+                for variant in &mut item.variants {
+                    variant.attrs.clear();
+                    strip_fields(&mut variant.fields);
+                }
+                item.into_token_stream()
+            },
+            syn::Item::Union(mut item) if has_repr(&item.attrs) => {
+                item.attrs.retain(is_repr);
+                item.vis = syn::Visibility::Inherited;
+                for field in &mut item.fields.named {
+                    field.attrs.clear();
+                    field.vis = syn::Visibility::Inherited;
+                }
+                item.into_token_stream()
+            },
+            syn::Item::Macro(item) => {
+                let is_definition = item.ident.is_some();
+                let tokens = strip_doc_attrs(item.mac.tokens);
+                if is_definition && !contains_abi_tokens(&tokens) {
+                    continue;
+                }
+                let path = item.mac.path.into_token_stream();
+                quote::quote!(#path ! { #tokens })
+            },
+            _ => continue,
+        };
+
+        schema.push(normalize(tokens));
+    }
+}
+
+/// Prints the tokens without any whitespace between them. The spacing of
+/// tokens depends on the source formatting and the version of `proc_macro2`.
+fn normalize(tokens: TokenStream) -> String {
+    let mut result = String::new();
+    for tree in tokens {
+        match tree {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => ("", ""),
+                };
+                result.push_str(open);
+                result.push_str(&normalize(group.stream()));
+                result.push_str(close);
+            },
+            // Identifiers are separated, to distinguish `a b` from `ab`
+            TokenTree::Ident(ident) => {
+                result.push_str(&ident.to_string());
+                result.push(' ');
+            },
+            TokenTree::Punct(punct) => result.push(punct.as_char()),
+            TokenTree::Literal(lit) => result.push_str(&lit.to_string()),
+        }
+    }
+    result
+}
+
+fn is_repr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("repr")
+}
+
+fn has_repr(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(is_repr)
+}
+
+fn strip_fields(fields: &mut syn::Fields) {
+    for field in fields.iter_mut() {
+        field.attrs.clear();
+        field.vis = syn::Visibility::Inherited;
+    }
+}
+
+/// Removes `#[doc = "..."]` and `#![doc = "..."]` attributes, which are created
+/// for doc comments inside macros.
+fn strip_doc_attrs(tokens: TokenStream) -> TokenStream {
+    let mut result = vec![];
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(tree) = iter.next() {
+        match tree {
+            TokenTree::Punct(punct) if punct.as_char() == '#' => {
+                let mut lookahead = iter.clone();
+                let is_inner = matches!(lookahead.peek(), Some(TokenTree::Punct(bang)) if bang.as_char() == '!');
+                if is_inner {
+                    lookahead.next();
+                }
+                if let Some(TokenTree::Group(group)) = lookahead.next() {
+                    if group.delimiter() == Delimiter::Bracket && is_doc_attr(&group.stream()) {
+                        iter = lookahead;
+                        continue;
+                    }
+                }
+                result.push(TokenTree::Punct(punct));
+            },
+            TokenTree::Group(group) => {
+                let mut stripped = proc_macro2::Group::new(group.delimiter(), strip_doc_attrs(group.stream()));
+                stripped.set_span(group.span());
+                result.push(TokenTree::Group(stripped));
+            },
+            tree => result.push(tree),
+        }
+    }
+    result.into_iter().collect()
+}
+
+fn is_doc_attr(tokens: &TokenStream) -> bool {
+    tokens.clone().into_iter().next().map_or(
+        false,
+        |first| matches!(first, TokenTree::Ident(ident) if ident == "doc"),
+    )
+}
+
+fn contains_abi_tokens(tokens: &TokenStream) -> bool {
+    tokens.clone().into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => ident == "repr" || ident == "extern",
+        TokenTree::Group(group) => contains_abi_tokens(&group.stream()),
+        _ => false,
+    })
+}
+
+/// FNV-1a, since the hash has to be stable across Rust versions
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
@@ -24,7 +24,12 @@ marker_api::export_lint_pass!(MarkerLintsLintPass);
 
 impl LintPass for MarkerLintsLintPass {
     fn info(&self) -> LintPassInfo {
-        LintPassInfoBuilder::new(Box::new([DIAG_MSG_UPPERCASE_START])).build()
+        LintPassInfoBuilder::new(Box::new([DIAG_MSG_UPPERCASE_START]))
+            .crate_name(env!("CARGO_PKG_NAME"))
+            .version(env!("CARGO_PKG_VERSION"))
+            .description(env!("CARGO_PKG_DESCRIPTION"))
+            .repository(env!("CARGO_PKG_REPOSITORY"))
+            .build()
     }
 
     fn check_expr<'ast>(&mut self, cx: &AstContext<'ast>, expr: ExprKind<'ast>) {
@@ -3,17 +3,26 @@ use std::cell::{OnceCell, RefCell};
 use marker_adapter::context::{DriverContext, DriverContextWrapper};
 use marker_api::{
     ast::{
-        item::{Body, ItemKind},
-        BodyId, ExprId, ItemId, Span, SpanId, SymbolId, TyDefId,
+        attr::Attribute,
+        expr::{ExprKind, MethodTarget},
+        generic::SemGenericArgKind,
+        item::{Body, ItemKind, SemItem},
+        pat::IdentPat,
+        stmt::StmtKind,
+        ty::SemTyKind,
+        BodyId, ConstValue, DefPath, DefPathId, ExpnInfo, ExprId, FileLocation, ItemId, NodeId, NodeKind, Span, SpanId,
+        SpanSrcId, StmtId, SymbolId, TyDefId, VarId, VariantId,
     },
     context::AstContext,
     diagnostic::{Diagnostic, EmissionNode},
     lint::{Level, Lint},
 };
+use rustc_errors::MultiSpan;
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
 use rustc_lint::LintStore;
 use rustc_middle::ty::TyCtxt;
+use rustc_trait_selection::infer::{InferCtxtExt, TyCtxtInferExt};
 
 use crate::conversion::{marker::MarkerConverter, rustc::RustcConverter};
 
@@ -43,6 +52,11 @@ pub struct RustcContext<'ast, 'tcx> {
     /// which makes it safe to access afterwards.
     ast_cx: OnceCell<&'ast AstContext<'ast>>,
     resolved_ty_ids: RefCell<FxHashMap<&'ast str, &'ast [TyDefId]>>,
+    resolved_fn_ids: RefCell<FxHashMap<&'ast str, &'ast [ItemId]>>,
+    resolved_const_ids: RefCell<FxHashMap<&'ast str, &'ast [ItemId]>>,
+    resolved_static_ids: RefCell<FxHashMap<&'ast str, &'ast [ItemId]>>,
+    resolved_trait_ids: RefCell<FxHashMap<&'ast str, &'ast [TyDefId]>>,
+    resolved_variant_ids: RefCell<FxHashMap<&'ast str, &'ast [VariantId]>>,
 }
 
 impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
@@ -56,6 +70,11 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
             rustc_converter: RustcConverter::new(rustc_cx, storage),
             ast_cx: OnceCell::new(),
             resolved_ty_ids: RefCell::default(),
+            resolved_fn_ids: RefCell::default(),
+            resolved_const_ids: RefCell::default(),
+            resolved_static_ids: RefCell::default(),
+            resolved_trait_ids: RefCell::default(),
+            resolved_variant_ids: RefCell::default(),
         });
 
         // Create and link `AstContext`
@@ -71,6 +90,76 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
         // The `OnceCell` is filled in the new function and can never be not set.
         self.ast_cx.get().unwrap()
     }
+
+    /// Resolves the given path and converts the resulting definitions with the
+    /// `convert` function. Definitions, for which `convert` returns `None`, are
+    /// filtered out. The results are cached in the given `cache`.
+    fn resolve_path_cached<T: Copy>(
+        &'ast self,
+        cache: &RefCell<FxHashMap<&'ast str, &'ast [T]>>,
+        path: &str,
+        convert: impl Fn(hir::def::DefKind, hir::def_id::DefId) -> Option<T>,
+    ) -> &'ast [T] {
+        // Caching
+        if let Some(ids) = cache.borrow().get(path) {
+            return ids;
+        }
+
+        let ids: Vec<_> = self
+            .resolve_path(path)
+            .into_iter()
+            .filter_map(|res| match res {
+                hir::def::Res::Def(kind, def_id) => convert(kind, def_id),
+                _ => None,
+            })
+            .collect();
+
+        // Allocation and caching
+        let ids = self.storage.alloc_slice(ids);
+        cache.borrow_mut().insert(self.storage.alloc_str(path), ids);
+        ids
+    }
+
+    fn resolve_path(&self, path: &str) -> Vec<hir::def::Res<hir::def_id::DefId>> {
+        // Path splitting and "validation"
+        let mut splits = path.split("::");
+        let Some(krate_name) = splits.next() else {
+            return vec![];
+        };
+        let segs: Vec<_> = splits.collect();
+        if segs.is_empty() {
+            return vec![];
+        }
+        // This code is inspired by `clippy_utils::def_path_res` without the special
+        // handling for primitive types
+        let tcx = self.rustc_cx;
+        let krate_name = rustc_span::Symbol::intern(krate_name);
+        let additional_krate: &[_] = if krate_name == rustc_span::symbol::kw::Crate {
+            &[hir::def_id::LOCAL_CRATE]
+        } else {
+            &[]
+        };
+        let krates = tcx
+            .crates(())
+            .iter()
+            .copied()
+            .chain(std::iter::once(hir::def_id::LOCAL_CRATE))
+            .filter(|id| tcx.crate_name(*id) == krate_name)
+            .chain(additional_krate.iter().copied());
+        let mut searches: Vec<_> = krates
+            .map(rustc_span::def_id::CrateNum::as_def_id)
+            .map(|id| hir::def::Res::Def::<hir::def_id::DefId>(tcx.def_kind(id), id))
+            .collect();
+
+        let mut rest = &segs[..];
+        while let [seg, next_rest @ ..] = rest {
+            rest = next_rest;
+            let seg = rustc_span::Symbol::intern(seg);
+            searches = select_children_with_name(tcx, &searches, seg);
+        }
+
+        searches
+    }
 }
 
 impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
@@ -84,15 +173,32 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         }
     }
 
+    fn report_lint_crate_panic(&'ast self, krate: &str, node: &str, span: Option<&Span<'ast>>) {
+        let msg = format!("the lint crate `{krate}` panicked while checking {node}");
+        let mut diag = match span {
+            Some(span) => self
+                .rustc_cx
+                .sess
+                .struct_span_warn(self.rustc_converter.to_span(span), msg),
+            None => self.rustc_cx.sess.struct_warn(msg),
+        };
+        diag.note("the lint crate has been disabled for the rest of this session");
+        diag.emit();
+    }
+
     fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>) {
         let Some(id) = self.rustc_converter.try_to_hir_id_from_emission_node(diag.node) else {
             return;
         };
         let lint = self.rustc_converter.to_lint(diag.lint);
+        let spans = std::iter::once(diag.span)
+            .chain(diag.additional_spans.get().iter().copied())
+            .map(|span| self.rustc_converter.to_span(span))
+            .collect();
         self.rustc_cx.struct_span_lint_hir(
             lint,
             id,
-            self.rustc_converter.to_span(diag.span),
+            MultiSpan::from_spans(spans),
             diag.msg().to_string(),
             |builder| {
                 for part in diag.parts.get() {
@@ -117,6 +223,20 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
                                 self.rustc_converter.to_applicability(*app),
                             );
                         },
+                        marker_api::diagnostic::DiagnosticPart::MultipartSuggestion { msg, parts, app } => {
+                            builder.multipart_suggestion(
+                                msg.get().to_string(),
+                                parts
+                                    .get()
+                                    .iter()
+                                    .map(|part| (self.rustc_converter.to_span(part.span), part.sugg.get().to_string()))
+                                    .collect(),
+                                self.rustc_converter.to_applicability(*app),
+                            );
+                        },
+                        marker_api::diagnostic::DiagnosticPart::SpanLabel { msg, span } => {
+                            builder.span_label(self.rustc_converter.to_span(span), msg.get().to_string());
+                        },
                         _ => unreachable!(),
                     }
                 }
@@ -126,8 +246,12 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
     }
 
     fn item(&'ast self, api_id: ItemId) -> Option<ItemKind<'ast>> {
-        let rustc_id = self.rustc_converter.to_item_id(api_id);
-        let rust_item = self.rustc_cx.hir().item(rustc_id);
+        // Ids can belong to items from other crates or to associated items, which
+        // are not stored as `hir::Item`s. These can't be converted by this method.
+        let local_id = self.rustc_converter.to_def_id(api_id).as_local()?;
+        let hir::Node::Item(rust_item) = self.rustc_cx.hir().find_by_def_id(local_id)? else {
+            return None;
+        };
         self.marker_converter.to_item(rust_item)
     }
 
@@ -136,84 +260,176 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         self.marker_converter.to_body(rustc_body)
     }
 
-    fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId] {
-        // Caching
-        if let Some(ids) = self.resolved_ty_ids.borrow().get(path) {
-            return ids;
-        }
+    fn eval_const(&'ast self, id: BodyId) -> &'ast ConstValue<'ast> {
+        self.marker_converter
+            .to_const_value_from_body(self.rustc_converter.to_body_id(id))
+    }
 
-        // Path splitting and "validation"
-        let mut splits = path.split("::");
-        let Some(krate_name) = splits.next() else {
-            return &[];
+    fn expr(&'ast self, id: ExprId) -> Option<ExprKind<'ast>> {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.try_to_expr(hir_id)
+    }
+
+    fn stmt(&'ast self, id: StmtId) -> Option<StmtKind<'ast>> {
+        let hir_id = self.rustc_converter.try_to_hir_id_from_emission_node(id.into())?;
+        self.marker_converter.try_to_stmt(id, hir_id)
+    }
+
+    fn var(&'ast self, id: VarId) -> Option<&'ast IdentPat<'ast>> {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.try_to_var(hir_id)
+    }
+
+    fn sem_item(&'ast self, id: DefPathId) -> Option<&'ast SemItem<'ast>> {
+        let def_id = match id {
+            DefPathId::Item(id) => self.rustc_converter.to_def_id(id),
+            DefPathId::TyDef(id) => self.rustc_converter.to_def_id(id),
+            DefPathId::Variant(_) => return None,
+            _ => unreachable!(),
         };
-        let segs: Vec<_> = splits.collect();
-        if segs.is_empty() {
-            return &[];
-        }
+        self.marker_converter.to_sem_item(def_id)
+    }
+
+    fn ancestors(&'ast self, id: NodeId) -> &'ast [NodeKind<'ast>] {
+        let hir_id = match id {
+            NodeId::Expr(id) => self.rustc_converter.try_to_hir_id_from_emission_node(id.into()),
+            NodeId::Item(id) => self.rustc_converter.try_to_hir_id_from_emission_node(id.into()),
+            NodeId::Stmt(id) => self.rustc_converter.try_to_hir_id_from_emission_node(id.into()),
+            NodeId::Field(id) => self.rustc_converter.try_to_hir_id_from_emission_node(id.into()),
+            NodeId::Variant(id) => self.rustc_converter.try_to_hir_id_from_emission_node(id.into()),
+            NodeId::Body(id) => {
+                let body = self.rustc_cx.hir().body(self.rustc_converter.to_body_id(id));
+                Some(body.value.hir_id)
+            },
+            _ => unreachable!(),
+        };
+        hir_id.map_or(&[], |hir_id| self.marker_converter.to_ancestors(hir_id, id))
+    }
+
+    fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId] {
         // This method is only intended to resolve `TyDefId`s, this means we can
         // ignore primitive types and all others which are specificity handled in
         // the `*TyKind` enums. Basically, we only need to find the ids of Enums,
         // Structs, Unions and maybe type aliases.
-        //
-        // This code is inspired by `clippy_utils::def_path_res` without the special
-        // handling for primitive types and other items
-        let tcx = self.rustc_cx;
-        let krate_name = rustc_span::Symbol::intern(krate_name);
-        let additional_krate: &[_] = if krate_name == rustc_span::symbol::kw::Crate {
-            &[hir::def_id::LOCAL_CRATE]
-        } else {
-            &[]
+        self.resolve_path_cached(&self.resolved_ty_ids, path, |kind, def_id| {
+            matches!(
+                kind,
+                hir::def::DefKind::Struct
+                    | hir::def::DefKind::Union
+                    | hir::def::DefKind::Enum
+                    | hir::def::DefKind::Trait
+                    | hir::def::DefKind::TyAlias
+            )
+            .then(|| self.marker_converter.to_ty_def_id(def_id))
+        })
+    }
+
+    fn resolve_fn_ids(&'ast self, path: &str) -> &'ast [ItemId] {
+        self.resolve_path_cached(&self.resolved_fn_ids, path, |kind, def_id| {
+            matches!(kind, hir::def::DefKind::Fn | hir::def::DefKind::AssocFn)
+                .then(|| self.marker_converter.to_item_id(def_id))
+        })
+    }
+
+    fn resolve_const_ids(&'ast self, path: &str) -> &'ast [ItemId] {
+        self.resolve_path_cached(&self.resolved_const_ids, path, |kind, def_id| {
+            matches!(kind, hir::def::DefKind::Const | hir::def::DefKind::AssocConst)
+                .then(|| self.marker_converter.to_item_id(def_id))
+        })
+    }
+
+    fn resolve_static_ids(&'ast self, path: &str) -> &'ast [ItemId] {
+        self.resolve_path_cached(&self.resolved_static_ids, path, |kind, def_id| {
+            matches!(kind, hir::def::DefKind::Static(_)).then(|| self.marker_converter.to_item_id(def_id))
+        })
+    }
+
+    fn resolve_trait_ids(&'ast self, path: &str) -> &'ast [TyDefId] {
+        self.resolve_path_cached(&self.resolved_trait_ids, path, |kind, def_id| {
+            matches!(kind, hir::def::DefKind::Trait).then(|| self.marker_converter.to_ty_def_id(def_id))
+        })
+    }
+
+    fn resolve_variant_ids(&'ast self, path: &str) -> &'ast [VariantId] {
+        self.resolve_path_cached(&self.resolved_variant_ids, path, |kind, def_id| match kind {
+            hir::def::DefKind::Variant => Some(self.marker_converter.to_variant_id(def_id)),
+            // Paths to tuple and unit variants, can resolve to their constructors
+            hir::def::DefKind::Ctor(hir::def::CtorOf::Variant, _) => {
+                Some(self.marker_converter.to_variant_id(self.rustc_cx.parent(def_id)))
+            },
+            _ => None,
+        })
+    }
+
+    fn def_path(&'ast self, id: DefPathId) -> &'ast DefPath<'ast> {
+        let def_id = match id {
+            DefPathId::Item(id) => self.rustc_converter.to_def_id(id),
+            DefPathId::TyDef(id) => self.rustc_converter.to_def_id(id),
+            DefPathId::Variant(id) => self.rustc_converter.to_def_id(id),
+            // `DefPathId` is non-exhaustive. Ids, which are unknown to this driver,
+            // are mapped to a placeholder path, which lint crates can still display.
+            _ => return self.storage.alloc(DefPath::new("{unknown}", &[])),
         };
-        let krates = tcx
-            .crates(())
-            .iter()
-            .copied()
-            .chain(std::iter::once(hir::def_id::LOCAL_CRATE))
-            .filter(|id| tcx.crate_name(*id) == krate_name)
-            .chain(additional_krate.iter().copied());
-        let mut searches: Vec<_> = krates
-            .map(rustc_span::def_id::CrateNum::as_def_id)
-            .map(|id| hir::def::Res::Def::<hir::def_id::DefId>(tcx.def_kind(id), id))
-            .collect();
+        self.marker_converter.to_def_path(def_id)
+    }
 
-        let mut rest = &segs[..];
-        while let [seg, next_rest @ ..] = rest {
-            rest = next_rest;
-            let seg = rustc_span::Symbol::intern(seg);
-            searches = select_children_with_name(tcx, &searches, seg);
+    fn implements_trait(
+        &'ast self,
+        api_ty: SemTyKind<'ast>,
+        trait_id: TyDefId,
+        api_args: &[SemGenericArgKind<'ast>],
+    ) -> bool {
+        let tcx = self.rustc_cx;
+        let trait_def_id = self.rustc_converter.to_def_id(trait_id);
+        if tcx.def_kind(trait_def_id) != hir::def::DefKind::Trait {
+            return false;
         }
+        let Some(ty) = self.rustc_converter.to_ty(api_ty) else {
+            return false;
+        };
+        let Some(substs) = self.rustc_converter.to_substs(trait_def_id, api_args, Some(ty)) else {
+            return false;
+        };
 
-        // Filtering to only take `DefId`s which are also `TyDefId`s
-        let ids: Vec<_> = searches
-            .into_iter()
-            .filter_map(|res| res.opt_def_id())
-            .filter(|def_id| {
-                matches!(
-                    tcx.def_kind(def_id),
-                    hir::def::DefKind::Struct
-                        | hir::def::DefKind::Union
-                        | hir::def::DefKind::Enum
-                        | hir::def::DefKind::Trait
-                        | hir::def::DefKind::TyAlias
-                )
-            })
-            .map(|def_id| self.marker_converter.to_ty_def_id(def_id))
-            .collect();
-
-        // Allocation and caching
-        let ids = self.storage.alloc_slice(ids);
-        self.resolved_ty_ids
-            .borrow_mut()
-            .insert(self.storage.alloc_str(path), ids);
-        ids
+        let arg_tys = api_args.iter().filter_map(|arg| {
+            if let SemGenericArgKind::Ty(ty) = arg {
+                Some(*ty)
+            } else {
+                None
+            }
+        });
+        let param_env = self
+            .rustc_converter
+            .to_param_env(&std::iter::once(api_ty).chain(arg_tys).collect::<Vec<_>>());
+        tcx.infer_ctxt()
+            .build()
+            .type_implements_trait(trait_def_id, substs, param_env)
+            .must_apply_modulo_regions()
     }
 
-    fn expr_ty(&'ast self, expr: ExprId) -> marker_api::ast::ty::SemTyKind<'ast> {
+    fn expr_ty(&'ast self, expr: ExprId) -> SemTyKind<'ast> {
         let hir_id = self.rustc_converter.to_hir_id(expr);
         self.marker_converter.expr_ty(hir_id)
     }
 
+    fn var_ty(&'ast self, var: VarId) -> SemTyKind<'ast> {
+        let hir_id = self.rustc_converter.to_hir_id(var);
+        self.marker_converter.var_ty(hir_id)
+    }
+
+    fn expr_attrs(&'ast self, expr: ExprId) -> &'ast [Attribute<'ast>] {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        self.marker_converter.to_attrs(hir_id)
+    }
+
+    fn is_exported(&'ast self, id: ItemId) -> bool {
+        let def_id = self.rustc_converter.to_def_id(id);
+        match def_id.as_local() {
+            Some(local_id) => self.rustc_cx.effective_visibilities(()).is_exported(local_id),
+            None => self.rustc_cx.visibility(def_id).is_public(),
+        }
+    }
+
     fn span(&'ast self, span_id: SpanId) -> &'ast Span<'ast> {
         let rustc_span = self.rustc_converter.to_span_from_id(span_id);
         self.storage.alloc(self.marker_converter.to_span(rustc_span))
@@ -225,6 +441,16 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         Some(self.storage.alloc_str(&snippet))
     }
 
+    fn span_file_location(&'ast self, api_span: &Span<'ast>) -> Option<&'ast FileLocation<'ast>> {
+        let rustc_span = self.rustc_converter.to_span(api_span);
+        self.marker_converter.to_file_location(rustc_span)
+    }
+
+    fn span_expn_info(&'ast self, src_id: SpanSrcId) -> Option<&'ast ExpnInfo<'ast>> {
+        let ctxt = self.rustc_converter.to_syntax_context(src_id);
+        self.marker_converter.to_expn_info(ctxt)
+    }
+
     fn symbol_str(&'ast self, api_id: SymbolId) -> &'ast str {
         let sym = self.rustc_converter.to_symbol(api_id);
         // The lifetime is fake, as documented in [`rustc_span::Span::as_str()`].
@@ -237,8 +463,9 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         api_str
     }
 
-    fn resolve_method_target(&'ast self, _id: ExprId) -> ItemId {
-        todo!()
+    fn resolve_method_target(&'ast self, id: ExprId) -> &'ast MethodTarget<'ast> {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.method_target(hir_id)
     }
 }
 
@@ -288,5 +515,35 @@ fn select_children_with_name(
             }
         });
 
+    // Associated items and enum variants
+    let select_assoc_items = |id, next_search: &mut Vec<_>| {
+        tcx.associated_items(id)
+            .filter_by_name_unhygienic(name)
+            .map(|item| hir::def::Res::Def(item.kind.as_def_kind(), item.def_id))
+            .collect_into(next_search);
+    };
+    for res in search {
+        match *res {
+            hir::def::Res::Def(hir::def::DefKind::Enum, id) => {
+                tcx.adt_def(id)
+                    .variants()
+                    .iter()
+                    .filter(|variant| variant.name == name)
+                    .map(|variant| hir::def::Res::Def(hir::def::DefKind::Variant, variant.def_id))
+                    .collect_into(&mut next_search);
+                for impl_id in tcx.inherent_impls(id) {
+                    select_assoc_items(*impl_id, &mut next_search);
+                }
+            },
+            hir::def::Res::Def(hir::def::DefKind::Struct | hir::def::DefKind::Union, id) => {
+                for impl_id in tcx.inherent_impls(id) {
+                    select_assoc_items(*impl_id, &mut next_search);
+                }
+            },
+            hir::def::Res::Def(hir::def::DefKind::Trait, id) => select_assoc_items(id, &mut next_search),
+            _ => {},
+        }
+    }
+
     next_search
 }
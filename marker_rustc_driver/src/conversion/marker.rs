@@ -3,23 +3,33 @@
 //! are implemented as methods of the [`MarkerConverterInner`] to group them
 //! together and share access to common objects easily.
 
+mod attr;
 mod common;
+mod const_value;
 mod expr;
 mod generics;
 mod item;
+mod node;
 mod pat;
+mod sem_item;
 mod stmts;
 mod ty;
 
+use attr::DeriveAttr;
+
 use std::cell::RefCell;
 
 use crate::context::storage::Storage;
 use marker_api::{
     ast::{
-        expr::ExprKind,
-        item::{Body, ItemKind},
+        attr::Attribute,
+        expr::{ExprKind, MethodTarget},
+        item::{Body, ItemKind, SemItem},
+        pat::{IdentPat, PatKind},
+        stmt::StmtKind,
         ty::SemTyKind,
-        BodyId, Crate, ExprId, ItemId, Span, SymbolId, TyDefId,
+        BodyId, ConstValue, Crate, DefPath, ExpnInfo, ExprId, FileLocation, ItemId, NodeId, NodeKind, Span, StmtId,
+        SymbolId, TyDefId, VarId, VariantId,
     },
     lint::Level,
 };
@@ -71,11 +81,37 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
         })
     }
 
+    pub fn var_ty(&self, id: hir::HirId) -> SemTyKind<'ast> {
+        // Parameters of trait functions without a body are not bound by a
+        // pattern. Their variable id is the id of the parameter type instead.
+        if let Some(hir::Node::Ty(_)) = self.inner.rustc_cx.hir().find(id) {
+            return self.inner.to_required_param_sem_ty(id);
+        }
+
+        self.expr_ty(id)
+    }
+
+    pub fn method_target(&self, id: hir::HirId) -> &'ast MethodTarget<'ast> {
+        self.with_body(id, |inner| inner.to_method_target(id))
+    }
+
+    forward_to_inner!(pub fn to_attrs(&self, hir_id: hir::HirId) -> &'ast [Attribute<'ast>]);
     forward_to_inner!(pub fn to_lint_level(&self, level: rustc_lint::Level) -> Level);
     forward_to_inner!(pub fn to_item(&self, rustc_item: &'tcx hir::Item<'tcx>) -> Option<ItemKind<'ast>>);
     forward_to_inner!(pub fn to_body(&self, body: &hir::Body<'tcx>) -> &'ast Body<'ast>);
+    forward_to_inner!(pub fn to_const_value_from_body(&self, body_id: hir::BodyId) -> &'ast ConstValue<'ast>);
+    forward_to_inner!(pub fn try_to_expr(&self, hir_id: hir::HirId) -> Option<ExprKind<'ast>>);
+    forward_to_inner!(pub fn try_to_stmt(&self, id: StmtId, hir_id: hir::HirId) -> Option<StmtKind<'ast>>);
+    forward_to_inner!(pub fn try_to_var(&self, hir_id: hir::HirId) -> Option<&'ast IdentPat<'ast>>);
+    forward_to_inner!(pub fn to_ancestors(&self, hir_id: hir::HirId, start: NodeId) -> &'ast [NodeKind<'ast>]);
+    forward_to_inner!(pub fn to_sem_item(&self, id: hir::def_id::DefId) -> Option<&'ast SemItem<'ast>>);
+    forward_to_inner!(pub fn to_def_path(&self, id: hir::def_id::DefId) -> &'ast DefPath<'ast>);
     forward_to_inner!(pub fn to_ty_def_id(&self, id: hir::def_id::DefId) -> TyDefId);
+    forward_to_inner!(pub fn to_item_id(&self, id: hir::def_id::DefId) -> ItemId);
+    forward_to_inner!(pub fn to_variant_id(&self, id: hir::def_id::DefId) -> VariantId);
     forward_to_inner!(pub fn to_span(&self, rustc_span: rustc_span::Span) -> Span<'ast>);
+    forward_to_inner!(pub fn to_file_location(&self, rustc_span: rustc_span::Span) -> Option<&'ast FileLocation<'ast>>);
+    forward_to_inner!(pub fn to_expn_info(&self, ctxt: rustc_span::SyntaxContext) -> Option<&'ast ExpnInfo<'ast>>);
     forward_to_inner!(pub fn to_crate(
         &self,
         rustc_crate_id: hir::def_id::CrateNum,
@@ -100,7 +136,18 @@ struct MarkerConverterInner<'ast, 'tcx> {
     items: RefCell<FxHashMap<ItemId, ItemKind<'ast>>>,
     bodies: RefCell<FxHashMap<BodyId, &'ast Body<'ast>>>,
     exprs: RefCell<FxHashMap<ExprId, ExprKind<'ast>>>,
+    stmts: RefCell<FxHashMap<StmtId, StmtKind<'ast>>>,
+    vars: RefCell<FxHashMap<VarId, Option<&'ast IdentPat<'ast>>>>,
+    /// Patterns don't have ids in marker. They are cached by their [`hir::HirId`],
+    /// to return the same pattern, when it's requested individually.
+    pats: RefCell<FxHashMap<hir::HirId, PatKind<'ast>>>,
     num_symbols: RefCell<FxHashMap<u32, SymbolId>>,
+    attrs: RefCell<FxHashMap<hir::HirId, &'ast [Attribute<'ast>]>>,
+    sem_items: RefCell<FxHashMap<hir::def_id::DefId, Option<&'ast SemItem<'ast>>>>,
+    def_paths: RefCell<FxHashMap<hir::def_id::DefId, &'ast DefPath<'ast>>>,
+    /// The `#[derive(...)]` attributes of local ADTs, which are reconstructed
+    /// from the derived impls. See [`Self::to_derive_attrs`].
+    derives: RefCell<Option<FxHashMap<hir::def_id::LocalDefId, Vec<DeriveAttr>>>>,
 
     /// Lang-items are weird, and if I'm being honest, I'm uncertain that I
     /// completely understand them. Anyways, here it goes, this is my current
@@ -137,6 +184,9 @@ struct MarkerConverterInner<'ast, 'tcx> {
     /// Requested on demand from rustc using a [`hir::BodyId`] see
     /// [`MarkerConverterInner::rustc_body`] for more information
     rustc_ty_check: RefCell<Option<&'tcx rustc_middle::ty::TypeckResults<'tcx>>>,
+    /// The item, which is used to resolve generic parameters in semantic types,
+    /// outside of bodies. This is set during the conversion of [`SemItem`]s.
+    rustc_generics_owner: RefCell<Option<hir::def_id::DefId>>,
 }
 
 // General util functions
@@ -148,10 +198,18 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             items: RefCell::default(),
             bodies: RefCell::default(),
             exprs: RefCell::default(),
+            stmts: RefCell::default(),
+            vars: RefCell::default(),
+            pats: RefCell::default(),
             num_symbols: RefCell::default(),
+            attrs: RefCell::default(),
+            sem_items: RefCell::default(),
+            def_paths: RefCell::default(),
+            derives: RefCell::default(),
             lang_item_map: RefCell::default(),
             rustc_body: RefCell::default(),
             rustc_ty_check: RefCell::default(),
+            rustc_generics_owner: RefCell::default(),
         };
 
         s.fill_create_lang_item_map();
@@ -236,6 +294,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         self.alloc(Crate::new(
             self.to_crate_id(rustc_crate_id),
             self.to_items(rustc_root_mod.item_ids),
+            self.to_attrs(hir::CRATE_HIR_ID),
         ))
     }
 }
@@ -0,0 +1,234 @@
+use marker_api::ast::{
+    attr::{
+        AttrArgs, AttrDelimiter, AttrStyle, Attribute, DelimitedAttrArgs, MetaItem, MetaItemKind, MetaLit, MetaLitKind,
+        NestedMetaItem,
+    },
+    Ident,
+};
+use rustc_ast as ast;
+use rustc_hash::FxHashMap;
+use rustc_hir as hir;
+use rustc_span::{
+    hygiene::{ExpnKind, MacroKind},
+    sym, Span, Symbol,
+};
+
+use super::MarkerConverterInner;
+
+/// A `#[derive(...)]` attribute, reconstructed from the derived impls.
+pub(super) struct DeriveAttr {
+    /// The span of the entire attribute.
+    span: Span,
+    /// The span and path of every derive macro in the attribute, like `Debug`.
+    derives: Vec<(Span, Symbol)>,
+}
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Returns the converted attributes attached to the node with the given [`hir::HirId`].
+    /// The results are cached, since attributes are requested lazily for some nodes.
+    #[must_use]
+    pub fn to_attrs(&self, hir_id: hir::HirId) -> &'ast [Attribute<'ast>] {
+        if let Some(attrs) = self.attrs.borrow().get(&hir_id) {
+            return attrs;
+        }
+
+        let rustc_attrs = self.rustc_cx.hir().attrs(hir_id);
+        let mut attrs: Vec<_> = rustc_attrs.iter().map(|attr| (attr.span, self.to_attr(attr))).collect();
+        if let Some(hir::Node::Item(item)) = self.rustc_cx.hir().find(hir_id)
+            && matches!(
+                item.kind,
+                hir::ItemKind::Struct(..) | hir::ItemKind::Enum(..) | hir::ItemKind::Union(..)
+            )
+        {
+            attrs.extend(self.to_derive_attrs(item.owner_id.def_id));
+            attrs.sort_by_key(|(span, _)| span.lo());
+        }
+        let attrs = self.alloc_slice(attrs.into_iter().map(|(_, attr)| attr));
+        self.attrs.borrow_mut().insert(hir_id, attrs);
+        attrs
+    }
+
+    /// `#[derive(...)]` attributes are removed from the HIR, during macro
+    /// expansion. They are reconstructed from the `#[automatically_derived]`
+    /// impls, which were created by them. Derive macros, which don't create an
+    /// impl for the ADT, are therefore not included in the reconstructed
+    /// attribute.
+    fn to_derive_attrs(&self, adt: hir::def_id::LocalDefId) -> Vec<(Span, Attribute<'ast>)> {
+        let mut derives = self.derives.borrow_mut();
+        let derives = derives.get_or_insert_with(|| self.collect_derive_attrs());
+        let Some(adt_derives) = derives.get(&adt) else {
+            return vec![];
+        };
+
+        adt_derives
+            .iter()
+            .map(|derive| {
+                let span = self.to_span_id(derive.span);
+                let path = self.alloc_slice([Ident::new(self.to_symbol_id(sym::derive), span)]);
+                let meta = self.alloc_slice(derive.derives.iter().map(|(path_span, name)| {
+                    let path = name
+                        .as_str()
+                        .split("::")
+                        .map(|seg| Ident::new(self.to_symbol_id(Symbol::intern(seg)), self.to_span_id(*path_span)))
+                        .collect::<Vec<_>>();
+                    NestedMetaItem::Meta(self.alloc(MetaItem::new(
+                        self.to_span_id(*path_span),
+                        self.alloc_slice(path),
+                        MetaItemKind::Word,
+                    )))
+                }));
+                let tokens = derive
+                    .derives
+                    .iter()
+                    .map(|(_, name)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let args = AttrArgs::Delimited(self.alloc(DelimitedAttrArgs::new(
+                    span,
+                    AttrDelimiter::Paren,
+                    self.to_symbol_id(Symbol::intern(&tokens)),
+                    Some(meta),
+                )));
+                (derive.span, Attribute::new(span, AttrStyle::Outer, false, path, args))
+            })
+            .collect()
+    }
+
+    fn collect_derive_attrs(&self) -> FxHashMap<hir::def_id::LocalDefId, Vec<DeriveAttr>> {
+        let mut map: FxHashMap<hir::def_id::LocalDefId, Vec<DeriveAttr>> = FxHashMap::default();
+        for &impl_id in self.rustc_cx.all_local_trait_impls(()).values().flatten() {
+            if !self.rustc_cx.has_attr(impl_id.to_def_id(), sym::automatically_derived) {
+                continue;
+            }
+            let Some(adt) = self.rustc_cx.type_of(impl_id).subst_identity().ty_adt_def() else {
+                continue;
+            };
+            let Some(adt_id) = adt.did().as_local() else {
+                continue;
+            };
+            // The derive macro is expanded from the `#[derive]` attribute, which
+            // is the parent expansion.
+            let expn = self.rustc_cx.def_span(impl_id).ctxt().outer_expn_data();
+            let ExpnKind::Macro(MacroKind::Derive, name) = expn.kind else {
+                continue;
+            };
+            let attr_span = expn.parent.expn_data().call_site;
+
+            let attrs = map.entry(adt_id).or_default();
+            let attr = if let Some(attr) = attrs.iter_mut().find(|attr| attr.span == attr_span) {
+                attr
+            } else {
+                attrs.push(DeriveAttr {
+                    span: attr_span,
+                    derives: vec![],
+                });
+                attrs.last_mut().unwrap()
+            };
+            // Some derives, like `PartialEq`, create multiple impls
+            if !attr.derives.iter().any(|(span, _)| *span == expn.call_site) {
+                attr.derives.push((expn.call_site, name));
+            }
+        }
+
+        for attrs in map.values_mut() {
+            attrs.sort_by_key(|attr| attr.span.lo());
+            for attr in attrs {
+                attr.derives.sort_by_key(|(span, _)| span.lo());
+            }
+        }
+        map
+    }
+
+    /// Returns the converted attributes of the definition with the given
+    /// [`DefId`](hir::def_id::DefId). For dependencies, this only includes the
+    /// attributes stored in the crate metadata.
+    #[must_use]
+    pub fn to_def_attrs(&self, def_id: hir::def_id::DefId) -> &'ast [Attribute<'ast>] {
+        if let Some(local_id) = def_id.as_local() {
+            return self.to_attrs(self.rustc_cx.hir().local_def_id_to_hir_id(local_id));
+        }
+
+        let rustc_attrs = self.rustc_cx.get_attrs_unchecked(def_id);
+        self.alloc_slice(rustc_attrs.iter().map(|attr| self.to_attr(attr)))
+    }
+
+    fn to_attr(&self, attr: &ast::Attribute) -> Attribute<'ast> {
+        let style = match attr.style {
+            ast::AttrStyle::Outer => AttrStyle::Outer,
+            ast::AttrStyle::Inner => AttrStyle::Inner,
+        };
+        let span = self.to_span_id(attr.span);
+
+        match &attr.kind {
+            ast::AttrKind::Normal(normal) => {
+                let item = &normal.item;
+                let args = match &item.args {
+                    ast::AttrArgs::Empty => AttrArgs::Empty,
+                    ast::AttrArgs::Delimited(delim_args) => {
+                        let meta = attr.meta_item_list().map(|list| self.to_nested_meta_items(&list));
+                        AttrArgs::Delimited(self.alloc(DelimitedAttrArgs::new(
+                            self.to_span_id(delim_args.dspan.entire()),
+                            self.to_attr_delimiter(delim_args.delim),
+                            self.to_symbol_id(rustc_span::Symbol::intern(&rustc_ast_pretty::pprust::tts_to_string(
+                                &delim_args.tokens,
+                            ))),
+                            meta,
+                        )))
+                    },
+                    ast::AttrArgs::Eq(_, ast::AttrArgsEq::Hir(lit)) => AttrArgs::Eq(self.alloc(self.to_meta_lit(lit))),
+                    ast::AttrArgs::Eq(_, ast::AttrArgsEq::Ast(_)) => {
+                        unreachable!("attribute arguments are lowered to literals in the HIR")
+                    },
+                };
+                Attribute::new(span, style, false, self.to_attr_path(&item.path), args)
+            },
+            ast::AttrKind::DocComment(_kind, sym) => {
+                let path = self.alloc_slice([Ident::new(
+                    self.to_symbol_id(rustc_span::sym::doc),
+                    self.to_span_id(attr.span),
+                )]);
+                let lit = self.alloc(MetaLit::new(span, MetaLitKind::Str(self.to_symbol_id(*sym))));
+                Attribute::new(span, style, true, path, AttrArgs::Eq(lit))
+            },
+        }
+    }
+
+    fn to_attr_delimiter(&self, delim: ast::MacDelimiter) -> AttrDelimiter {
+        match delim {
+            ast::MacDelimiter::Parenthesis => AttrDelimiter::Paren,
+            ast::MacDelimiter::Bracket => AttrDelimiter::Bracket,
+            ast::MacDelimiter::Brace => AttrDelimiter::Brace,
+        }
+    }
+
+    fn to_attr_path(&self, path: &ast::Path) -> &'ast [Ident<'ast>] {
+        self.alloc_slice(path.segments.iter().map(|seg| self.to_ident(seg.ident)))
+    }
+
+    fn to_nested_meta_items(&self, items: &[ast::NestedMetaItem]) -> &'ast [NestedMetaItem<'ast>] {
+        self.alloc_slice(items.iter().map(|item| match item {
+            ast::NestedMetaItem::MetaItem(meta) => NestedMetaItem::Meta(self.alloc(self.to_meta_item(meta))),
+            ast::NestedMetaItem::Lit(lit) => NestedMetaItem::Lit(self.alloc(self.to_meta_lit(lit))),
+        }))
+    }
+
+    fn to_meta_item(&self, meta: &ast::MetaItem) -> MetaItem<'ast> {
+        let kind = match &meta.kind {
+            ast::MetaItemKind::Word => MetaItemKind::Word,
+            ast::MetaItemKind::List(items) => MetaItemKind::List(self.to_nested_meta_items(items).into()),
+            ast::MetaItemKind::NameValue(lit) => MetaItemKind::NameValue(self.alloc(self.to_meta_lit(lit))),
+        };
+        MetaItem::new(self.to_span_id(meta.span), self.to_attr_path(&meta.path), kind)
+    }
+
+    fn to_meta_lit(&self, lit: &ast::MetaItemLit) -> MetaLit<'ast> {
+        let kind = match lit.kind {
+            ast::LitKind::Str(sym, _) => MetaLitKind::Str(self.to_symbol_id(sym)),
+            ast::LitKind::Int(value, _) => MetaLitKind::Int(value),
+            ast::LitKind::Bool(value) => MetaLitKind::Bool(value),
+            ast::LitKind::Char(value) => MetaLitKind::Char(value),
+            _ => MetaLitKind::Other,
+        };
+        MetaLit::new(self.to_span_id(lit.span), kind)
+    }
+}
@@ -3,9 +3,9 @@ use std::mem::{size_of, transmute};
 use marker_api::ast::generic::SynGenericArgs;
 use marker_api::ast::ty::SynTyKind;
 use marker_api::ast::{
-    Abi, AstPath, AstPathSegment, AstPathTarget, AstQPath, BodyId, Constness, CrateId, ExprId, FieldId, GenericId,
-    Ident, ItemId, LetStmtId, Mutability, Safety, Span, SpanId, SpanSource, SpanSrcId, SymbolId, Syncness, TraitRef,
-    TyDefId, VarId, VariantId,
+    Abi, AstPath, AstPathSegment, AstPathTarget, AstQPath, BodyId, Constness, CrateId, DefPath, ExpnInfo, ExpnKind,
+    ExprId, FieldId, FileLocation, FilePos, GenericId, Ident, ItemId, LetStmtId, Mutability, Safety, Span, SpanId,
+    SpanSource, SpanSrcId, StmtId, StmtIdInner, SymbolId, Syncness, TraitRef, TyDefId, VarId, VariantId,
 };
 use marker_api::lint::Level;
 use rustc_hir as hir;
@@ -132,6 +132,17 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         transmute_id!(HirIdLayout as LetStmtId = id.into())
     }
 
+    #[must_use]
+    pub fn to_stmt_id(&self, stmt: &hir::Stmt<'_>) -> StmtId {
+        match stmt.kind {
+            hir::StmtKind::Local(local) => StmtId::new(StmtIdInner::LetStmt(self.to_let_stmt_id(local.hir_id))),
+            hir::StmtKind::Item(item) => StmtId::new(StmtIdInner::Item(self.to_item_id(item))),
+            hir::StmtKind::Expr(expr) | hir::StmtKind::Semi(expr) => {
+                StmtId::new(StmtIdInner::Expr(self.to_expr_id(expr.hir_id)))
+            },
+        }
+    }
+
     #[must_use]
     pub fn to_span_src_id(&self, id: rustc_span::SyntaxContext) -> SpanSrcId {
         transmute_id!(rustc_span::SyntaxContext as SpanSrcId = id)
@@ -392,6 +403,116 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         TraitRef::new(trait_id, self.to_syn_generic_args_from_path(trait_ref.path))
     }
 
+    #[must_use]
+    pub fn to_file_location(&self, rustc_span: rustc_span::Span) -> Option<&'ast FileLocation<'ast>> {
+        let map = self.rustc_cx.sess.source_map();
+        let rustc_span = rustc_span.source_callsite();
+        let file = map.lookup_source_file(rustc_span.lo());
+        let rustc_span::FileName::Real(real_name) = &file.name else {
+            return None;
+        };
+        let name = real_name.local_path_if_available().to_string_lossy();
+
+        let get_line = |line_index: usize| {
+            file.get_line(line_index)
+                .map(|line| line.trim_end_matches('\r').to_string())
+        };
+        let to_pos = |pos: rustc_span::BytePos| -> Option<(usize, FilePos)> {
+            let line_index = file.lookup_line(pos)?;
+            let line = get_line(line_index)?;
+            let col_utf8 = ((pos - file.line_bounds(line_index).start).0 as usize).min(line.len());
+            let col_utf16 = line.get(..col_utf8)?.encode_utf16().count();
+            Some((line_index, FilePos::new(line_index + 1, col_utf8, col_utf16)))
+        };
+        let (start_line, start) = to_pos(rustc_span.lo())?;
+        let (end_line, end) = to_pos(rustc_span.hi())?;
+
+        let lines: Option<Vec<_>> = (start_line..=end_line)
+            .map(|index| get_line(index).map(|line| self.storage.alloc_str(&line).into()))
+            .collect();
+        Some(self.alloc(FileLocation::new(
+            self.storage.alloc_str(&name),
+            start,
+            end,
+            self.alloc_slice(lines?),
+        )))
+    }
+
+    /// Returns the [`DefPath`] of the given definition. The results are cached,
+    /// since lint crates commonly request the paths of the same definitions
+    /// over and over again.
+    #[must_use]
+    pub fn to_def_path(&self, def_id: hir::def_id::DefId) -> &'ast DefPath<'ast> {
+        if let Some(path) = self.def_paths.borrow().get(&def_id) {
+            return path;
+        }
+
+        let path = self.to_def_path_uncached(def_id);
+        self.def_paths.borrow_mut().insert(def_id, path);
+        path
+    }
+
+    fn to_def_path_uncached(&self, def_id: hir::def_id::DefId) -> &'ast DefPath<'ast> {
+        let tcx = self.rustc_cx;
+        let mut segments = vec![];
+        let mut current = Some(def_id);
+        while let Some(id) = current {
+            let key = tcx.def_key(id);
+            match key.disambiguated_data.data {
+                hir::definitions::DefPathData::CrateRoot
+                | hir::definitions::DefPathData::ForeignMod
+                | hir::definitions::DefPathData::Ctor => {},
+                hir::definitions::DefPathData::Impl => {
+                    let self_ty = tcx.type_of(id).subst_identity();
+                    let name = match self_ty.kind() {
+                        rustc_middle::ty::TyKind::Adt(adt, _) => tcx.item_name(adt.did()).to_string(),
+                        _ => self_ty.to_string(),
+                    };
+                    segments.push(name);
+                },
+                data => match data.get_opt_name() {
+                    Some(name) => segments.push(name.to_string()),
+                    None => segments.push(key.disambiguated_data.to_string()),
+                },
+            }
+            current = key.parent.map(|index| hir::def_id::DefId { krate: id.krate, index });
+        }
+
+        let krate = self.storage.alloc_str(tcx.crate_name(def_id.krate).as_str());
+        let segments = self.alloc_slice(segments.iter().rev().map(|seg| self.storage.alloc_str(seg).into()));
+        self.alloc(DefPath::new(krate, segments))
+    }
+
+    #[must_use]
+    pub fn to_expn_info(&self, ctxt: rustc_span::SyntaxContext) -> Option<&'ast ExpnInfo<'ast>> {
+        let data = ctxt.outer_expn_data();
+        let (kind, name) = match data.kind {
+            rustc_span::ExpnKind::Root => return None,
+            rustc_span::ExpnKind::Macro(kind, name) => {
+                let kind = match kind {
+                    rustc_span::MacroKind::Bang => ExpnKind::Bang,
+                    rustc_span::MacroKind::Attr => ExpnKind::Attr,
+                    rustc_span::MacroKind::Derive => ExpnKind::Derive,
+                };
+                (kind, name)
+            },
+            rustc_span::ExpnKind::AstPass(pass) => (ExpnKind::Compiler, rustc_span::Symbol::intern(pass.descr())),
+            rustc_span::ExpnKind::Desugaring(desugar) => {
+                (ExpnKind::Desugaring, rustc_span::Symbol::intern(desugar.descr()))
+            },
+        };
+
+        let def_crate = data.macro_def_id.map(|def_id| def_id.krate);
+        Some(self.alloc(ExpnInfo::new(
+            kind,
+            self.to_symbol_id(name),
+            def_crate.map(|krate| self.to_crate_id(krate)),
+            def_crate.map(|krate| self.to_symbol_id(self.rustc_cx.crate_name(krate))),
+            def_crate == Some(hir::def_id::LOCAL_CRATE),
+            self.to_span_id(data.call_site),
+        )))
+    }
+
     pub fn to_span(&self, rustc_span: rustc_span::Span) -> Span<'ast> {
         let ((src, src_info), span) = self.to_span_info(rustc_span);
         let start = (span.lo().0 as usize) - src_info.rustc_start_offset;
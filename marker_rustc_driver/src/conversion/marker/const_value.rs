@@ -0,0 +1,110 @@
+use marker_api::ast::{ConstInt, ConstStr, ConstValue};
+use rustc_hir as hir;
+use rustc_middle as mid;
+use rustc_middle::mir::interpret::{self, AllocRange, GlobalAlloc, Scalar};
+use rustc_target::abi::Size;
+
+use super::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Evaluates the constant, which owns the given body. Bodies of functions,
+    /// closures and statics are not evaluated.
+    #[must_use]
+    pub fn to_const_value_from_body(&self, body_id: hir::BodyId) -> &'ast ConstValue<'ast> {
+        let map = self.rustc_cx.hir();
+        let owner = map.body_owner_def_id(body_id);
+        let value = match map.body_owner_kind(owner) {
+            hir::BodyOwnerKind::Const => {
+                let ty = self.rustc_cx.type_of(owner).subst_identity();
+                // Errors are reported by rustc. Constants, which depend on
+                // generic parameters, will also return an error.
+                match self.rustc_cx.const_eval_poly(owner.to_def_id()) {
+                    Ok(value) => self.to_const_value(value, ty),
+                    Err(_) => ConstValue::TooComplex,
+                }
+            },
+            hir::BodyOwnerKind::Fn | hir::BodyOwnerKind::Closure | hir::BodyOwnerKind::Static(_) => {
+                ConstValue::TooComplex
+            },
+        };
+        self.alloc(value)
+    }
+
+    /// Converts constants from the type system, like array lengths and const
+    /// generic arguments. Only values, which have already been evaluated by
+    /// rustc, are converted.
+    #[must_use]
+    pub fn to_const_value_from_ty_const(&self, value: mid::ty::Const<'tcx>) -> ConstValue<'ast> {
+        match value.kind() {
+            mid::ty::ConstKind::Value(mid::ty::ValTree::Leaf(int)) => self.to_const_value_from_scalar(int, value.ty()),
+            _ => ConstValue::TooComplex,
+        }
+    }
+
+    fn to_const_value(&self, value: interpret::ConstValue<'tcx>, ty: mid::ty::Ty<'tcx>) -> ConstValue<'ast> {
+        match ty.kind() {
+            mid::ty::Bool | mid::ty::Char | mid::ty::Int(_) | mid::ty::Uint(_) => value
+                .try_to_scalar_int()
+                .map_or(ConstValue::TooComplex, |int| self.to_const_value_from_scalar(int, ty)),
+            mid::ty::Ref(_, inner, _) => match inner.kind() {
+                mid::ty::Str => match value {
+                    interpret::ConstValue::Slice { .. } => ConstValue::Str(self.to_const_str(value)),
+                    _ => ConstValue::TooComplex,
+                },
+                mid::ty::Slice(elem) if *elem == self.rustc_cx.types.u8 => match value {
+                    interpret::ConstValue::Slice { .. } => ConstValue::ByteStr(self.to_const_str(value)),
+                    _ => ConstValue::TooComplex,
+                },
+                mid::ty::Array(elem, len) if *elem == self.rustc_cx.types.u8 => self
+                    .to_const_byte_str_from_array(value, *len)
+                    .map_or(ConstValue::TooComplex, ConstValue::ByteStr),
+                _ => ConstValue::TooComplex,
+            },
+            _ => ConstValue::TooComplex,
+        }
+    }
+
+    fn to_const_value_from_scalar(&self, int: mid::ty::ScalarInt, ty: mid::ty::Ty<'tcx>) -> ConstValue<'ast> {
+        match ty.kind() {
+            mid::ty::Bool => bool::try_from(int).map_or(ConstValue::TooComplex, ConstValue::Bool),
+            mid::ty::Char => char::try_from(int).map_or(ConstValue::TooComplex, ConstValue::Char),
+            mid::ty::Int(int_ty) => {
+                let bits = int.size().sign_extend(int.assert_bits(int.size()));
+                ConstValue::Int(ConstInt::new(bits, self.to_num_kind_from_int(*int_ty)))
+            },
+            mid::ty::Uint(uint_ty) => {
+                let bits = int.assert_bits(int.size());
+                ConstValue::Int(ConstInt::new(bits, self.to_num_kind_from_uint(*uint_ty)))
+            },
+            _ => ConstValue::TooComplex,
+        }
+    }
+
+    fn to_const_str(&self, value: interpret::ConstValue<'tcx>) -> ConstStr<'ast> {
+        let bytes = interpret::get_slice_bytes(&self.rustc_cx, value);
+        ConstStr::new(self.alloc_slice(bytes.iter().copied()))
+    }
+
+    /// Byte string literals have the type `&[u8; N]`. Their value is a pointer
+    /// to the allocation, which holds the bytes.
+    fn to_const_byte_str_from_array(
+        &self,
+        value: interpret::ConstValue<'tcx>,
+        len: mid::ty::Const<'tcx>,
+    ) -> Option<ConstStr<'ast>> {
+        let Some(Scalar::Ptr(ptr, _)) = value.try_to_scalar() else {
+            return None;
+        };
+        let len = len.try_to_target_usize(self.rustc_cx)?;
+        let (alloc_id, offset) = ptr.into_parts();
+        let GlobalAlloc::Memory(alloc) = self.rustc_cx.try_get_global_alloc(alloc_id)? else {
+            return None;
+        };
+        let range = AllocRange {
+            start: offset,
+            size: Size::from_bytes(len),
+        };
+        let bytes = alloc.inner().get_bytes_strip_provenance(&self.rustc_cx, range).ok()?;
+        Some(ConstStr::new(self.alloc_slice(bytes.iter().copied())))
+    }
+}
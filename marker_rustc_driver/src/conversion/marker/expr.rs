@@ -4,17 +4,18 @@ use marker_api::{
             ArrayExpr, AsExpr, AssignExpr, AwaitExpr, BinaryOpExpr, BinaryOpKind, BlockExpr, BoolLitExpr, BreakExpr,
             CallExpr, CaptureKind, CharLitExpr, ClosureExpr, ClosureParam, CommonExprData, ConstExpr, ContinueExpr,
             CtorExpr, CtorField, ExprKind, ExprPrecedence, FieldExpr, FloatLitExpr, FloatSuffix, ForExpr, IfExpr,
-            IndexExpr, IntLitExpr, IntSuffix, LetExpr, LoopExpr, MatchArm, MatchExpr, MethodExpr, PathExpr,
-            QuestionMarkExpr, RangeExpr, RefExpr, ReturnExpr, StrLitData, StrLitExpr, TupleExpr, UnaryOpExpr,
-            UnaryOpKind, UnstableExpr, WhileExpr,
+            IndexExpr, IntLitExpr, IntSuffix, LetExpr, LoopExpr, MatchArm, MatchExpr, MethodExpr, MethodSource,
+            MethodTarget, PathExpr, QuestionMarkExpr, RangeExpr, RefExpr, ReturnExpr, StrLitData, StrLitExpr,
+            TupleExpr, UnaryOpExpr, UnaryOpKind, UnstableExpr, WhileExpr,
         },
         pat::PatKind,
-        Ident, Safety, Syncness,
+        ExprId, Ident, Safety, Syncness,
     },
     CtorBlocker,
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_middle as mid;
 use std::str::FromStr;
 
 use super::MarkerConverterInner;
@@ -230,13 +231,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             hir::ExprKind::Break(dest, expr) => ExprKind::Break(self.alloc(BreakExpr::new(
                 data,
                 dest.label.map(|label| self.to_ident(label.ident)),
-                self.to_expr_id(dest.target_id.expect("rustc would have errored")),
+                self.to_jump_target_id(dest),
                 expr.map(|expr| self.to_expr(expr)),
             ))),
             hir::ExprKind::Continue(dest) => ExprKind::Continue(self.alloc(ContinueExpr::new(
                 data,
                 dest.label.map(|label| self.to_ident(label.ident)),
-                self.to_expr_id(dest.target_id.expect("rustc would have errored")),
+                self.to_jump_target_id(dest),
             ))),
             hir::ExprKind::Ret(expr) => {
                 ExprKind::Return(self.alloc(ReturnExpr::new(data, expr.map(|expr| self.to_expr(expr)))))
@@ -276,6 +277,16 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     }
 
     #[must_use]
+    /// Labeled blocks are targeted by the [`hir::HirId`] of the block, while
+    /// marker uses the id of the expression containing the block.
+    fn to_jump_target_id(&self, dest: &hir::Destination) -> ExprId {
+        let target = dest.target_id.expect("rustc would have errored");
+        match self.rustc_cx.hir().find(target) {
+            Some(hir::Node::Block(_)) => self.to_expr_id(self.rustc_cx.hir().parent_id(target)),
+            _ => self.to_expr_id(target),
+        }
+    }
+
     fn to_block_expr(
         &self,
         data: CommonExprData<'ast>,
@@ -408,6 +419,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             self.to_pat(arm.pat),
             guard,
             self.to_expr(arm.body),
+            self.to_attrs(arm.hir_id),
         )
     }
 
@@ -688,6 +700,45 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 
     pub fn to_const_expr(&self, anon: hir::AnonConst) -> ConstExpr<'ast> {
         let body = self.rustc_cx.hir().body(anon.body);
-        self.with_body(body.id(), || ConstExpr::new(self.to_expr(body.value)))
+        self.with_body(body.id(), || {
+            ConstExpr::new(self.to_body_id(body.id()), self.to_expr(body.value))
+        })
+    }
+
+    /// Resolves the method called by the [`hir::ExprKind::MethodCall`] expression
+    /// with the given [`hir::HirId`]. This requires the typeck results of the
+    /// enclosing body to be available.
+    ///
+    /// Calls of trait methods are resolved to the method in the `impl` block, if
+    /// the implementation is known. The trait method is used for generic
+    /// receivers and for default methods, which are not overridden by the
+    /// implementation.
+    #[must_use]
+    pub fn to_method_target(&self, hir_id: hir::HirId) -> &'ast MethodTarget<'ast> {
+        let ty_check = self.rustc_ty_check();
+        let mut method_id = ty_check
+            .type_dependent_def_id(hir_id)
+            .expect("method calls are always resolved during type checking");
+        let mut substs = ty_check.node_substs(hir_id);
+        if self.rustc_cx.trait_of_item(method_id).is_some() {
+            let owner = self.rustc_cx.hir().enclosing_body_owner(hir_id);
+            let param_env = self.rustc_cx.param_env(owner);
+            let erased_substs = self.rustc_cx.erase_regions(substs);
+            if let Ok(Some(instance)) = mid::ty::Instance::resolve(self.rustc_cx, param_env, method_id, erased_substs)
+                && let mid::ty::InstanceDef::Item(impl_method_id) = instance.def
+                && self.rustc_cx.impl_of_method(impl_method_id).is_some()
+            {
+                method_id = impl_method_id;
+                substs = instance.substs;
+            }
+        }
+        let parent_id = self.rustc_cx.parent(method_id);
+        let source = match self.rustc_cx.def_kind(parent_id) {
+            hir::def::DefKind::Impl { .. } => MethodSource::Impl(self.to_item_id(parent_id)),
+            hir::def::DefKind::Trait => MethodSource::Trait(self.to_ty_def_id(parent_id)),
+            kind => unreachable!("methods are always defined in impls or traits, not in: {kind:?}"),
+        };
+        let generics = self.to_sem_generic_args(substs);
+        self.alloc(MethodTarget::new(self.to_item_id(method_id), source, generics))
     }
 }
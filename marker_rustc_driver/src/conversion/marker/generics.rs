@@ -5,7 +5,7 @@ use marker_api::ast::{
         SynGenericParams, SynLifetimeArg, SynLifetimeClause, SynLifetimeParam, SynTraitBound, SynTyArg, SynTyClause,
         SynTyParam, SynTyParamBound, SynWhereClauseKind,
     },
-    ConstValue, TraitRef,
+    TraitRef,
 };
 use rustc_hir as hir;
 use rustc_middle as mid;
@@ -46,8 +46,8 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         match &arg.unpack() {
             mid::ty::GenericArgKind::Lifetime(_) => None,
             mid::ty::GenericArgKind::Type(ty) => Some(SemGenericArgKind::Ty(self.to_sem_ty(*ty))),
-            mid::ty::GenericArgKind::Const(_) => Some(SemGenericArgKind::Const(
-                self.alloc(SemConstArg::new(ConstValue::new())),
+            mid::ty::GenericArgKind::Const(value) => Some(SemGenericArgKind::Const(
+                self.alloc(SemConstArg::new(self.to_const_value_from_ty_const(*value))),
             )),
         }
     }
@@ -212,6 +212,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 };
                 let id = self.to_generic_id(rustc_param.def_id);
                 let span = self.to_span_id(rustc_param.span);
+                let attrs = self.to_attrs(rustc_param.hir_id);
                 match rustc_param.kind {
                     hir::GenericParamKind::Lifetime {
                         kind: hir::LifetimeParamKind::Explicit,
@@ -219,9 +220,10 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         id,
                         name,
                         Some(span),
+                        attrs,
                     )))),
                     hir::GenericParamKind::Type { synthetic: false, .. } => Some(SynGenericParamKind::Ty(
-                        self.alloc(SynTyParam::new(Some(span), name, id)),
+                        self.alloc(SynTyParam::new(Some(span), name, id, attrs)),
                     )),
                     hir::GenericParamKind::Const { ty, default } => {
                         Some(SynGenericParamKind::Const(self.alloc(SynConstParam::new(
@@ -230,6 +232,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                             self.to_syn_ty(ty),
                             default.map(|anon| self.to_const_expr(anon)),
                             span,
+                            attrs,
                         ))))
                     },
                     _ => None,
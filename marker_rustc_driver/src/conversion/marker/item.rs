@@ -4,9 +4,10 @@ use marker_api::{
         item::{
             AdtKind, AssocItemKind, Body, CommonItemData, ConstItem, EnumItem, EnumVariant, ExternBlockItem,
             ExternCrateItem, ExternItemKind, Field, FnItem, FnParam, ImplItem, ItemKind, ModItem, StaticItem,
-            StructItem, TraitItem, TyAliasItem, UnionItem, UnstableItem, UseItem, UseKind, Visibility,
+            StructItem, TraitItem, TyAliasItem, UnionItem, UnstableItem, UseItem, UseKind, Visibility, VisibilityKind,
         },
         pat::{CommonPatData, IdentPat, PatKind},
+        ty::SemTyKind,
         Abi, Constness, Mutability, Safety, Syncness,
     },
     CtorBlocker,
@@ -45,7 +46,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         let ident = self.to_ident(rustc_item.ident);
-        let data = CommonItemData::new(id, self.to_span_id(rustc_item.span), ident);
+        let data = CommonItemData::new(
+            id,
+            self.to_span_id(rustc_item.span),
+            self.to_visibility(rustc_item.owner_id.def_id, rustc_item.vis_span),
+            ident,
+            self.to_attrs(rustc_item.hir_id()),
+        );
         let item = match &rustc_item.kind {
             hir::ItemKind::ExternCrate(original_name) => ItemKind::ExternCrate(self.alloc({
                 ExternCrateItem::new(data, self.to_symbol_id(original_name.unwrap_or(rustc_item.ident.name)))
@@ -114,6 +121,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         self.to_span_id(variant.span),
                         self.to_adt_kind(&variant.data),
                         variant.disr_expr.map(|anon| self.to_const_expr(anon)),
+                        self.to_attrs(variant.hir_id),
                     )
                 }));
                 ItemKind::Enum(self.alloc(EnumItem::new(data, self.to_syn_generic_params(generics), variants)))
@@ -213,15 +221,19 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 self.alloc_slice(idents.iter().zip(decl.inputs.iter()).map(|(ident, ty)| {
                     FnParam::new(
                         self.to_span_id(ident.span.to(ty.span)),
+                        // These parameters are not bound to a variable. The id of
+                        // the type is used instead, to provide the semantic type
+                        // via `IdentPat::ty()`.
                         PatKind::Ident(self.alloc(IdentPat::new(
                             CommonPatData::new(self.to_span_id(ident.span)),
                             self.to_symbol_id(ident.name),
-                            self.to_var_id(hir::HirId::INVALID),
+                            self.to_var_id(ty.hir_id),
                             Mutability::Unmut,
                             false,
                             None,
                         ))),
                         self.to_syn_ty(ty),
+                        &[],
                     )
                 }))
             },
@@ -229,13 +241,41 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 let body = self.rustc_cx.hir().body(body_id);
                 self.with_body(body_id, || {
                     self.alloc_slice(body.params.iter().zip(decl.inputs.iter()).map(|(param, ty)| {
-                        FnParam::new(self.to_span_id(param.span), self.to_pat(param.pat), self.to_syn_ty(ty))
+                        FnParam::new(
+                            self.to_span_id(param.span),
+                            self.to_pat(param.pat),
+                            self.to_syn_ty(ty),
+                            self.to_attrs(param.hir_id),
+                        )
                     }))
                 })
             },
         }
     }
 
+    /// Returns the semantic type of a parameter of a trait function without a
+    /// body. The parameter is identified by the [`hir::HirId`] of its type.
+    #[must_use]
+    pub fn to_required_param_sem_ty(&self, ty_id: hir::HirId) -> SemTyKind<'ast> {
+        let owner = ty_id.owner.def_id;
+        let decl = self
+            .rustc_cx
+            .hir()
+            .fn_decl_by_hir_id(hir::HirId::make_owner(owner))
+            .expect("the owner of a parameter type is a function");
+        let index = decl
+            .inputs
+            .iter()
+            .position(|ty| ty.hir_id == ty_id)
+            .expect("the type is a parameter type of the function");
+        let input = self.rustc_cx.fn_sig(owner).subst_identity().skip_binder().inputs()[index];
+
+        let prev_owner = self.rustc_generics_owner.replace(Some(owner.to_def_id()));
+        let ty = self.to_sem_ty(input);
+        self.rustc_generics_owner.replace(prev_owner);
+        ty
+    }
+
     fn to_adt_kind(&self, var_data: &'tcx hir::VariantData) -> AdtKind<'ast> {
         match var_data {
             hir::VariantData::Struct(fields, _recovered) => AdtKind::Field(self.to_fields(fields).into()),
@@ -246,18 +286,44 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 
     fn to_fields(&self, fields: &'tcx [hir::FieldDef]) -> &'ast [Field<'ast>] {
         self.alloc_slice(fields.iter().map(|field| {
-            // FIXME update Visibility creation to use the stored local def id inside the
-            // field after the next sync. See #55
             Field::new(
                 self.to_field_id(field.hir_id),
-                Visibility::new(self.to_item_id(field.def_id)),
+                self.to_visibility(field.def_id, field.vis_span),
                 self.to_symbol_id(field.ident.name),
                 self.to_syn_ty(field.ty),
                 self.to_span_id(field.span),
+                self.to_attrs(field.hir_id),
             )
         }))
     }
 
+    fn to_visibility(&self, def_id: hir::def_id::LocalDefId, vis_span: rustc_span::Span) -> Visibility<'ast> {
+        let id = self.to_item_id(def_id);
+        // Rustc uses an empty span, if no visibility has been declared
+        if vis_span.is_empty() {
+            return Visibility::new(id, None, VisibilityKind::Inherited);
+        }
+
+        let kind = match self.rustc_cx.visibility(def_id) {
+            rustc_middle::ty::Visibility::Public => VisibilityKind::Public,
+            rustc_middle::ty::Visibility::Restricted(module) => {
+                // The declaration is resolved to the module, the item is restricted
+                // to. The kind is determined by the relation to the item's module.
+                let parent = self.rustc_cx.parent_module_from_def_id(def_id);
+                if module.is_crate_root() {
+                    VisibilityKind::Crate
+                } else if module != parent.to_def_id()
+                    && module == self.rustc_cx.parent_module_from_def_id(parent).to_def_id()
+                {
+                    VisibilityKind::Super
+                } else {
+                    VisibilityKind::Path(self.to_item_id(module))
+                }
+            },
+        };
+        Visibility::new(id, Some(self.to_span_id(vis_span)), kind)
+    }
+
     fn to_external_items(&self, items: &'tcx [hir::ForeignItemRef], abi: Abi) -> &'ast [ExternItemKind<'ast>] {
         self.alloc_slice(items.iter().map(|item| self.to_external_item(item, abi)))
     }
@@ -274,7 +340,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         let foreign_item = self.rustc_cx.hir().foreign_item(rustc_item.id);
-        let data = CommonItemData::new(id, self.to_span_id(rustc_item.span), self.to_ident(rustc_item.ident));
+        let data = CommonItemData::new(
+            id,
+            self.to_span_id(rustc_item.span),
+            self.to_visibility(rustc_item.id.owner_id.def_id, foreign_item.vis_span),
+            self.to_ident(rustc_item.ident),
+            self.to_attrs(rustc_item.id.hir_id()),
+        );
         let item = match &foreign_item.kind {
             hir::ForeignItemKind::Fn(decl, idents, generics) => {
                 let return_ty = if let hir::FnRetTy::Return(rust_ty) = decl.output {
@@ -334,7 +406,14 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         let trait_item = self.rustc_cx.hir().trait_item(rustc_item.id);
-        let data = CommonItemData::new(id, self.to_span_id(rustc_item.span), self.to_ident(rustc_item.ident));
+        let data = CommonItemData::new(
+            id,
+            self.to_span_id(rustc_item.span),
+            // Trait items use the visibility of the trait
+            self.to_visibility(rustc_item.id.owner_id.def_id, rustc_span::DUMMY_SP),
+            self.to_ident(rustc_item.ident),
+            self.to_attrs(rustc_item.id.hir_id()),
+        );
 
         let item = match &trait_item.kind {
             hir::TraitItemKind::Const(ty, body_id) => AssocItemKind::Const(
@@ -383,7 +462,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         let impl_item = self.rustc_cx.hir().impl_item(rustc_item.id);
-        let data = CommonItemData::new(id, self.to_span_id(rustc_item.span), self.to_ident(rustc_item.ident));
+        let data = CommonItemData::new(
+            id,
+            self.to_span_id(rustc_item.span),
+            self.to_visibility(rustc_item.id.owner_id.def_id, impl_item.vis_span),
+            self.to_ident(rustc_item.ident),
+            self.to_attrs(rustc_item.id.hir_id()),
+        );
 
         let item = match &impl_item.kind {
             hir::ImplItemKind::Const(ty, body_id) => AssocItemKind::Const(
@@ -431,6 +516,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         // Yield expressions are currently unstable
         if let Some(hir::GeneratorKind::Gen) = body.generator_kind {
             return self.alloc(Body::new(
+                id,
                 self.to_item_id(self.rustc_cx.hir().body_owner_def_id(body.id())),
                 expr::ExprKind::Unstable(self.alloc(expr::UnstableExpr::new(
                     expr::CommonExprData::new(self.to_expr_id(body.value.hir_id), self.to_span_id(body.value.span)),
@@ -441,7 +527,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 
         self.with_body(body.id(), || {
             let owner = self.to_item_id(self.rustc_cx.hir().body_owner_def_id(body.id()));
-            let api_body = self.alloc(Body::new(owner, self.to_expr(body.value)));
+            let api_body = self.alloc(Body::new(id, owner, self.to_expr(body.value)));
             self.bodies.borrow_mut().insert(id, api_body);
             api_body
         })
@@ -0,0 +1,181 @@
+use marker_api::ast::{
+    expr::ExprKind,
+    item::{Field, ItemKind},
+    pat::{IdentPat, PatKind},
+    stmt::StmtKind,
+    NodeId, NodeKind, StmtId,
+};
+use rustc_hir as hir;
+
+use super::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Returns the ancestors of the node with the given [`hir::HirId`], starting
+    /// with the parent. Nodes which are not represented in marker, like match arms
+    /// or nodes created by desugaring, are skipped. The `start` node itself is
+    /// filtered out, since some marker nodes share the [`hir::HirId`] with their
+    /// first child, like expression statements and their expression.
+    #[must_use]
+    pub fn to_ancestors(&self, hir_id: hir::HirId, start: NodeId) -> &'ast [NodeKind<'ast>] {
+        let map = self.rustc_cx.hir();
+        self.fill_body_caches(hir_id);
+
+        let mut nodes = vec![];
+        let mut child = hir_id;
+        for (id, node) in map.parent_iter(hir_id) {
+            if let Some(body_id) = node.body_id() {
+                let body = map.body(body_id);
+                if body.value.hir_id == child {
+                    nodes.push(NodeKind::Body(self.to_body(body)));
+                }
+            }
+            if let Some(api_node) = self.to_node(id, node) {
+                nodes.push(api_node);
+            }
+            child = id;
+        }
+
+        nodes.retain(|node| node.id() != Some(start));
+        self.alloc_slice(nodes)
+    }
+
+    /// Returns the expression with the given [`hir::HirId`], if it has been
+    /// converted as part of its body.
+    #[must_use]
+    pub fn try_to_expr(&self, hir_id: hir::HirId) -> Option<ExprKind<'ast>> {
+        self.fill_body_caches(hir_id);
+        self.exprs.borrow().get(&self.to_expr_id(hir_id)).copied()
+    }
+
+    /// Returns the statement with the given [`StmtId`], if it has been converted
+    /// as part of its body. The [`hir::HirId`] is used to find the body.
+    #[must_use]
+    pub fn try_to_stmt(&self, id: StmtId, hir_id: hir::HirId) -> Option<StmtKind<'ast>> {
+        self.fill_body_caches(hir_id);
+        self.stmts.borrow().get(&id).copied()
+    }
+
+    /// Returns the [`IdentPat`], which binds the variable with the given
+    /// [`hir::HirId`]. Patterns are not cached during the conversion of bodies,
+    /// the binding is therefore converted again and cached separately.
+    #[must_use]
+    pub fn try_to_var(&self, hir_id: hir::HirId) -> Option<&'ast IdentPat<'ast>> {
+        let id = self.to_var_id(hir_id);
+        if let Some(var) = self.vars.borrow().get(&id) {
+            return *var;
+        }
+
+        let map = self.rustc_cx.hir();
+        let var = match map.find(hir_id) {
+            Some(hir::Node::Pat(pat)) if matches!(pat.kind, hir::PatKind::Binding(..)) => {
+                let body_id = map.body_owned_by(map.enclosing_body_owner(hir_id));
+                if let PatKind::Ident(ident) = self.with_body(body_id, || self.to_pat(pat)) {
+                    Some(ident)
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        self.vars.borrow_mut().insert(id, var);
+        var
+    }
+
+    /// Expressions and statements are only taken from the cache, as they might
+    /// be desugared. Converting the bodies first ensures that the cache is
+    /// filled, even if the lint crate requested a node of an unvisited body.
+    fn fill_body_caches(&self, hir_id: hir::HirId) {
+        let map = self.rustc_cx.hir();
+        for (_, node) in map.parent_iter(hir_id) {
+            if let Some(body_id) = node.body_id() {
+                self.to_body(map.body(body_id));
+            }
+        }
+    }
+
+    fn to_node(&self, id: hir::HirId, node: hir::Node<'tcx>) -> Option<NodeKind<'ast>> {
+        let map = self.rustc_cx.hir();
+        match node {
+            hir::Node::Item(_) | hir::Node::ImplItem(_) | hir::Node::TraitItem(_) | hir::Node::ForeignItem(_) => {
+                self.to_item_from_owner(id.owner.def_id).map(NodeKind::Item)
+            },
+            hir::Node::Variant(variant) => {
+                let variant_id = self.to_variant_id(variant.def_id);
+                let ItemKind::Enum(item) = self.to_item_from_owner(map.get_parent_item(id).def_id)? else {
+                    return None;
+                };
+                item.variants()
+                    .iter()
+                    .find(|api_variant| api_variant.id() == variant_id)
+                    .map(NodeKind::Variant)
+            },
+            hir::Node::Field(field) => {
+                let field_id = self.to_field_id(field.hir_id);
+                let find_field =
+                    |fields: &'ast [Field<'ast>]| fields.iter().find(|api_field| api_field.id() == field_id);
+                let api_field = match self.to_item_from_owner(map.get_parent_item(id).def_id)? {
+                    ItemKind::Struct(item) => find_field(item.fields()),
+                    ItemKind::Union(item) => find_field(item.fields()),
+                    ItemKind::Enum(item) => item.variants().iter().find_map(|variant| find_field(variant.fields())),
+                    ItemKind::Mod(_)
+                    | ItemKind::ExternCrate(_)
+                    | ItemKind::Use(_)
+                    | ItemKind::Static(_)
+                    | ItemKind::Const(_)
+                    | ItemKind::Fn(_)
+                    | ItemKind::TyAlias(_)
+                    | ItemKind::Trait(_)
+                    | ItemKind::Impl(_)
+                    | ItemKind::ExternBlock(_)
+                    | ItemKind::Unstable(_) => None,
+                    _ => unreachable!(),
+                };
+                api_field.map(NodeKind::Field)
+            },
+            hir::Node::Stmt(stmt) => self
+                .stmts
+                .borrow()
+                .get(&self.to_stmt_id(stmt))
+                .copied()
+                .map(NodeKind::Stmt),
+            hir::Node::Expr(expr) => self
+                .exprs
+                .borrow()
+                .get(&self.to_expr_id(expr.hir_id))
+                .copied()
+                .map(NodeKind::Expr),
+            hir::Node::Pat(pat) if pat.span.desugaring_kind().is_none() => {
+                // Patterns are cached by their `HirId`. The body is still required,
+                // in case the pattern hasn't been converted yet.
+                let body_id = map.body_owned_by(map.enclosing_body_owner(id));
+                Some(NodeKind::Pat(self.with_body(body_id, || self.to_pat(pat))))
+            },
+            _ => None,
+        }
+    }
+
+    /// Associated and foreign items are converted as part of their parent item.
+    /// This converts the outermost item, if needed, and then returns the requested
+    /// item from the cache.
+    fn to_item_from_owner(&self, def_id: hir::def_id::LocalDefId) -> Option<ItemKind<'ast>> {
+        let id = self.to_item_id(def_id);
+        if let Some(item) = self.items.borrow().get(&id) {
+            return Some(*item);
+        }
+
+        let map = self.rustc_cx.hir();
+        let mut owner = def_id;
+        loop {
+            match map.find_by_def_id(owner)? {
+                hir::Node::Item(item) => {
+                    let _ = self.to_item(item);
+                    break;
+                },
+                _ => owner = map.get_parent_item(map.local_def_id_to_hir_id(owner)).def_id,
+            }
+        }
+
+        self.items.borrow().get(&id).copied()
+    }
+}
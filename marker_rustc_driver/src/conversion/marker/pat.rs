@@ -18,9 +18,18 @@ thread_local! {
 }
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Converts the given pattern. The result is cached by the [`hir::HirId`] of
+    /// the pattern, since patterns can also be requested individually, for
+    /// example as ancestors of other nodes.
     #[must_use]
     pub fn to_pat(&self, pat: &hir::Pat<'tcx>) -> PatKind<'ast> {
-        DEFAULT_LHS_MAP.with(|map| self.to_pat_with_hls(pat, map))
+        if let Some(pat) = self.pats.borrow().get(&pat.hir_id) {
+            return *pat;
+        }
+
+        let api_pat = DEFAULT_LHS_MAP.with(|map| self.to_pat_with_hls(pat, map));
+        self.pats.borrow_mut().insert(pat.hir_id, api_pat);
+        api_pat
     }
 
     #[must_use]
@@ -29,10 +38,8 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         pat: &hir::Pat<'tcx>,
         lhs_map: &FxHashMap<hir::HirId, ExprKind<'ast>>,
     ) -> PatKind<'ast> {
-        // Here we don't need to take special care for caching, as marker patterns
-        // don't have IDs and can't be requested individually. Instead patterns are
-        // stored as part of their parent expressions or items. Not needing to deal
-        // with caching makes this implementation simpler.
+        // Patterns with a custom `lhs_map` are only created for assignments and
+        // are therefore not cached. Other patterns are cached by `to_pat`.
         let data = CommonPatData::new(self.to_span_id(pat.span));
 
         match &pat.kind {
@@ -0,0 +1,214 @@
+use marker_api::ast::{
+    generic::{SemGenericParam, SemGenericParamKind},
+    item::{
+        SemAdtItem, SemAdtKind, SemConstItem, SemField, SemFnItem, SemImplItem, SemItem, SemItemKind, SemModItem,
+        SemStaticItem, SemTraitItem, SemTyAliasItem, SemVariant, VisibilityKind,
+    },
+    ItemId,
+};
+use rustc_hir as hir;
+use rustc_middle as mid;
+
+use super::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Returns the semantic view of the item with the given [`DefId`](hir::def_id::DefId).
+    /// This only uses queries, which are also available for items from dependencies.
+    /// The results are cached, since the view is requested lazily by lint crates.
+    #[must_use]
+    pub fn to_sem_item(&self, def_id: hir::def_id::DefId) -> Option<&'ast SemItem<'ast>> {
+        if let Some(item) = self.sem_items.borrow().get(&def_id) {
+            return *item;
+        }
+
+        // Semantic types of generic parameters are resolved with the generics
+        // of the owner. The owner is reset afterwards, in case this is called
+        // during the conversion of another item.
+        let prev_owner = self.rustc_generics_owner.replace(Some(def_id));
+        let item = self.to_sem_item_uncached(def_id);
+        self.rustc_generics_owner.replace(prev_owner);
+
+        self.sem_items.borrow_mut().insert(def_id, item);
+        item
+    }
+
+    fn to_sem_item_uncached(&self, def_id: hir::def_id::DefId) -> Option<&'ast SemItem<'ast>> {
+        let tcx = self.rustc_cx;
+        let def_kind = tcx.opt_def_kind(def_id)?;
+        let kind = match def_kind {
+            hir::def::DefKind::Mod => SemItemKind::Mod(self.alloc(SemModItem::new(self.to_sem_mod_items(def_id)))),
+            hir::def::DefKind::Fn | hir::def::DefKind::AssocFn => {
+                SemItemKind::Fn(self.alloc(self.to_sem_fn_item(def_id, def_kind)))
+            },
+            hir::def::DefKind::Const | hir::def::DefKind::AssocConst => {
+                SemItemKind::Const(self.alloc(SemConstItem::new(self.to_sem_ty(self.sem_type_of(def_id)))))
+            },
+            hir::def::DefKind::Static(mutability) => SemItemKind::Static(self.alloc(SemStaticItem::new(
+                self.to_sem_ty(self.sem_type_of(def_id)),
+                self.to_mutability(mutability),
+            ))),
+            hir::def::DefKind::TyAlias => {
+                SemItemKind::TyAlias(self.alloc(SemTyAliasItem::new(Some(self.to_sem_ty(self.sem_type_of(def_id))))))
+            },
+            hir::def::DefKind::AssocTy => {
+                let aliased_ty = tcx
+                    .associated_item(def_id)
+                    .defaultness(tcx)
+                    .has_value()
+                    .then(|| self.to_sem_ty(self.sem_type_of(def_id)));
+                SemItemKind::TyAlias(self.alloc(SemTyAliasItem::new(aliased_ty)))
+            },
+            hir::def::DefKind::Struct | hir::def::DefKind::Enum | hir::def::DefKind::Union => {
+                SemItemKind::Adt(self.alloc(self.to_sem_adt_item(def_id)))
+            },
+            hir::def::DefKind::Trait => SemItemKind::Trait(self.alloc(SemTraitItem::new(
+                self.to_ty_def_id(def_id),
+                self.to_safety(tcx.trait_def(def_id).unsafety),
+                self.to_sem_assoc_items(def_id),
+            ))),
+            hir::def::DefKind::Impl { .. } => SemItemKind::Impl(
+                self.alloc(SemImplItem::new(
+                    self.to_sem_ty(self.sem_type_of(def_id)),
+                    tcx.impl_trait_ref(def_id)
+                        .map(|trait_ref| self.to_ty_def_id(trait_ref.skip_binder().def_id)),
+                    self.to_sem_assoc_items(def_id),
+                )),
+            ),
+            hir::def::DefKind::Macro(_)
+            | hir::def::DefKind::ExternCrate
+            | hir::def::DefKind::Use
+            | hir::def::DefKind::ForeignMod
+            | hir::def::DefKind::ForeignTy
+            | hir::def::DefKind::TraitAlias
+            | hir::def::DefKind::OpaqueTy
+            | hir::def::DefKind::GlobalAsm => SemItemKind::Other,
+            // Variants, fields, generic parameters and bodies are not items
+            _ => return None,
+        };
+
+        // Rustc doesn't store generics for all items in the crate metadata
+        let generics = match def_kind {
+            hir::def::DefKind::Mod
+            | hir::def::DefKind::Macro(_)
+            | hir::def::DefKind::ExternCrate
+            | hir::def::DefKind::Use
+            | hir::def::DefKind::ForeignMod
+            | hir::def::DefKind::GlobalAsm => &[],
+            _ => self.to_sem_generic_params(def_id),
+        };
+        let name = tcx.opt_item_name(def_id).map(|name| self.to_symbol_id(name));
+        let visibility = if matches!(def_kind, hir::def::DefKind::Impl { .. }) {
+            VisibilityKind::Inherited
+        } else {
+            self.to_sem_visibility(def_id)
+        };
+
+        Some(self.alloc(SemItem::new(
+            self.to_item_id(def_id),
+            name,
+            visibility,
+            self.to_def_attrs(def_id),
+            generics,
+            kind,
+        )))
+    }
+
+    fn sem_type_of(&self, def_id: hir::def_id::DefId) -> mid::ty::Ty<'tcx> {
+        self.rustc_cx.type_of(def_id).subst_identity()
+    }
+
+    fn to_sem_visibility(&self, def_id: hir::def_id::DefId) -> VisibilityKind {
+        match self.rustc_cx.visibility(def_id) {
+            mid::ty::Visibility::Public => VisibilityKind::Public,
+            mid::ty::Visibility::Restricted(module) if module.is_crate_root() => VisibilityKind::Crate,
+            mid::ty::Visibility::Restricted(module) => VisibilityKind::Path(self.to_item_id(module)),
+        }
+    }
+
+    fn to_sem_generic_params(&self, def_id: hir::def_id::DefId) -> &'ast [SemGenericParam<'ast>] {
+        let generics = self.rustc_cx.generics_of(def_id);
+        let params: Vec<_> = generics
+            .params
+            .iter()
+            // The `Self` parameter of traits is implicit
+            .filter(|param| !(generics.has_self && param.index == 0))
+            .map(|param| {
+                let kind = match param.kind {
+                    mid::ty::GenericParamDefKind::Lifetime => SemGenericParamKind::Lifetime,
+                    mid::ty::GenericParamDefKind::Type { .. } => SemGenericParamKind::Ty,
+                    mid::ty::GenericParamDefKind::Const { .. } => SemGenericParamKind::Const,
+                };
+                SemGenericParam::new(self.to_generic_id(param.def_id), self.to_symbol_id(param.name), kind)
+            })
+            .collect();
+        self.alloc_slice(params)
+    }
+
+    fn to_sem_mod_items(&self, def_id: hir::def_id::DefId) -> &'ast [ItemId] {
+        let tcx = self.rustc_cx;
+        let children = match def_id.as_local() {
+            Some(local_id) => tcx.module_children_local(local_id),
+            None => tcx.module_children(def_id),
+        };
+        let items: Vec<_> = children
+            .iter()
+            .filter_map(|child| match child.res {
+                hir::def::Res::Def(hir::def::DefKind::Ctor(..), _) => None,
+                hir::def::Res::Def(_, id) => Some(self.to_item_id(id)),
+                _ => None,
+            })
+            .collect();
+        self.alloc_slice(items)
+    }
+
+    fn to_sem_assoc_items(&self, def_id: hir::def_id::DefId) -> &'ast [ItemId] {
+        let ids = self.rustc_cx.associated_item_def_ids(def_id);
+        self.alloc_slice(ids.iter().map(|id| self.to_item_id(*id)))
+    }
+
+    fn to_sem_fn_item(&self, def_id: hir::def_id::DefId, def_kind: hir::def::DefKind) -> SemFnItem<'ast> {
+        let tcx = self.rustc_cx;
+        let sig = tcx.fn_sig(def_id).subst_identity().skip_binder();
+        let has_self =
+            matches!(def_kind, hir::def::DefKind::AssocFn) && tcx.associated_item(def_id).fn_has_self_parameter;
+        SemFnItem::new(
+            self.alloc_slice(sig.inputs().iter().map(|ty| self.to_sem_ty(*ty))),
+            self.to_sem_ty(sig.output()),
+            self.to_constness(tcx.constness(def_id)),
+            self.to_syncness(tcx.asyncness(def_id)),
+            self.to_safety(sig.unsafety),
+            has_self,
+        )
+    }
+
+    fn to_sem_adt_item(&self, def_id: hir::def_id::DefId) -> SemAdtItem<'ast> {
+        let adt = self.rustc_cx.adt_def(def_id);
+        let adt_kind = match adt.adt_kind() {
+            mid::ty::AdtKind::Struct => SemAdtKind::Struct,
+            mid::ty::AdtKind::Union => SemAdtKind::Union,
+            mid::ty::AdtKind::Enum => SemAdtKind::Enum,
+        };
+        // Rustc stores `#[non_exhaustive]` of structs and unions on their variant
+        let is_non_exhaustive = if adt.is_enum() {
+            adt.is_variant_list_non_exhaustive()
+        } else {
+            adt.non_enum_variant().is_field_list_non_exhaustive()
+        };
+        let variants = self.alloc_slice(adt.variants().iter().map(|variant| {
+            let fields = self.alloc_slice(variant.fields.iter().map(|field| {
+                SemField::new(
+                    self.to_symbol_id(field.name),
+                    self.to_sem_visibility(field.did),
+                    self.to_sem_ty(self.sem_type_of(field.did)),
+                )
+            }));
+            SemVariant::new(
+                self.to_symbol_id(variant.name),
+                variant.is_field_list_non_exhaustive(),
+                fields,
+            )
+        }));
+
+        SemAdtItem::new(self.to_ty_def_id(def_id), adt_kind, is_non_exhaustive, variants)
+    }
+}
@@ -8,7 +8,12 @@ use super::MarkerConverterInner;
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     pub fn to_stmt(&self, stmt: &hir::Stmt<'tcx>) -> Option<StmtKind<'ast>> {
-        match &stmt.kind {
+        let id = self.to_stmt_id(stmt);
+        if let Some(api_stmt) = self.stmts.borrow().get(&id) {
+            return Some(*api_stmt);
+        }
+
+        let api_stmt = match &stmt.kind {
             hir::StmtKind::Local(local) => match local.source {
                 hir::LocalSource::Normal => Some(StmtKind::Let(self.alloc(LetStmt::new(
                     self.to_let_stmt_id(local.hir_id),
@@ -17,6 +22,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     local.ty.map(|ty| self.to_syn_ty(ty)),
                     local.init.map(|init| self.to_expr(init)),
                     local.els.map(|els| self.to_expr_from_block(els)),
+                    self.to_attrs(local.hir_id),
                 )))),
                 hir::LocalSource::AssignDesugar(_) => {
                     unreachable!("this will be handled by the block expr wrapping the desugar")
@@ -32,6 +38,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             hir::StmtKind::Expr(expr) | hir::StmtKind::Semi(expr) => {
                 Some(StmtKind::Expr(self.alloc(self.to_expr(expr)), CtorBlocker::new()))
             },
-        }
+        }?;
+
+        self.stmts.borrow_mut().insert(id, api_stmt);
+        Some(api_stmt)
     }
 }
@@ -6,7 +6,7 @@ use marker_api::ast::{
         SynNumTy, SynPathTy, SynRawPtrTy, SynRefTy, SynSliceTy, SynTextTy, SynTraitObjTy, SynTupleTy, SynTyKind,
         TextKind,
     },
-    CommonCallableData, ConstValue, Constness, Parameter, Syncness,
+    CommonCallableData, Constness, Parameter, Syncness,
 };
 use rustc_hir as hir;
 use rustc_middle as mid;
@@ -22,26 +22,10 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             mid::ty::TyKind::Bool => SemTyKind::Bool(self.alloc(SemBoolTy::new())),
             mid::ty::TyKind::Char => SemTyKind::Text(self.alloc(SemTextTy::new(TextKind::Char))),
             mid::ty::TyKind::Int(int_ty) => {
-                let num_ty = match int_ty {
-                    mid::ty::IntTy::Isize => NumKind::Isize,
-                    mid::ty::IntTy::I8 => NumKind::I8,
-                    mid::ty::IntTy::I16 => NumKind::I16,
-                    mid::ty::IntTy::I32 => NumKind::I32,
-                    mid::ty::IntTy::I64 => NumKind::I64,
-                    mid::ty::IntTy::I128 => NumKind::I128,
-                };
-                SemTyKind::Num(self.alloc(SemNumTy::new(num_ty)))
+                SemTyKind::Num(self.alloc(SemNumTy::new(self.to_num_kind_from_int(*int_ty))))
             },
             mid::ty::TyKind::Uint(uint_ty) => {
-                let num_ty = match uint_ty {
-                    mid::ty::UintTy::Usize => NumKind::Usize,
-                    mid::ty::UintTy::U8 => NumKind::U8,
-                    mid::ty::UintTy::U16 => NumKind::U16,
-                    mid::ty::UintTy::U32 => NumKind::U32,
-                    mid::ty::UintTy::U64 => NumKind::U64,
-                    mid::ty::UintTy::U128 => NumKind::U128,
-                };
-                SemTyKind::Num(self.alloc(SemNumTy::new(num_ty)))
+                SemTyKind::Num(self.alloc(SemNumTy::new(self.to_num_kind_from_uint(*uint_ty))))
             },
             mid::ty::TyKind::Float(float_ty) => {
                 let num_ty = match float_ty {
@@ -58,9 +42,10 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             mid::ty::TyKind::Foreign(_) => {
                 todo!("foreign type are currently sadly not supported. See rust-marker/marker#182")
             },
-            mid::ty::TyKind::Array(inner, _len) => {
-                SemTyKind::Array(self.alloc(SemArrayTy::new(self.to_sem_ty(*inner), ConstValue::new())))
-            },
+            mid::ty::TyKind::Array(inner, len) => SemTyKind::Array(self.alloc(SemArrayTy::new(
+                self.to_sem_ty(*inner),
+                self.to_const_value_from_ty_const(*len),
+            ))),
             mid::ty::TyKind::Slice(inner) => SemTyKind::Slice(self.alloc(SemSliceTy::new(self.to_sem_ty(*inner)))),
             mid::ty::TyKind::Tuple(ty_lst) => SemTyKind::Tuple(self.alloc(SemTupleTy::new(
                 self.alloc_slice(ty_lst.iter().map(|ty| self.to_sem_ty(ty))),
@@ -108,18 +93,17 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 SemTyKind::Alias(self.alloc(SemAliasTy::new(self.to_item_id(info.def_id))))
             },
             mid::ty::TyKind::Param(param) => {
-                let body_id = self
-                    .rustc_body
-                    .borrow()
-                    .expect("semantic `TyKind::Param` is only valid inside bodies");
-                // This is a local id, this makes sense, since rustc only accesses
-                // expressions and therefore semantic types of the current crate.
-                // This should be fine...
-                let owner = self.rustc_cx.hir().body_owner_def_id(body_id);
-                let generic_info = self
-                    .rustc_cx
-                    .generics_of(owner.to_def_id())
-                    .type_param(param, self.rustc_cx);
+                // Semantic items provide their own owner, since they can be
+                // from other crates. Otherwise, the type belongs to an expression
+                // and therefore to the current body.
+                let owner = self.rustc_generics_owner.borrow().unwrap_or_else(|| {
+                    let body_id = self
+                        .rustc_body
+                        .borrow()
+                        .expect("semantic `TyKind::Param` is only valid inside bodies or semantic items");
+                    self.rustc_cx.hir().body_owner_def_id(body_id).to_def_id()
+                });
+                let generic_info = self.rustc_cx.generics_of(owner).type_param(param, self.rustc_cx);
                 SemTyKind::Generic(self.alloc(SemGenericTy::new(self.to_generic_id(generic_info.def_id))))
             },
             mid::ty::TyKind::Bound(_, _) => {
@@ -131,6 +115,30 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             mid::ty::TyKind::Error(_) => unreachable!("would have triggered a rustc error"),
         }
     }
+
+    #[must_use]
+    pub fn to_num_kind_from_int(&self, int_ty: mid::ty::IntTy) -> NumKind {
+        match int_ty {
+            mid::ty::IntTy::Isize => NumKind::Isize,
+            mid::ty::IntTy::I8 => NumKind::I8,
+            mid::ty::IntTy::I16 => NumKind::I16,
+            mid::ty::IntTy::I32 => NumKind::I32,
+            mid::ty::IntTy::I64 => NumKind::I64,
+            mid::ty::IntTy::I128 => NumKind::I128,
+        }
+    }
+
+    #[must_use]
+    pub fn to_num_kind_from_uint(&self, uint_ty: mid::ty::UintTy) -> NumKind {
+        match uint_ty {
+            mid::ty::UintTy::Usize => NumKind::Usize,
+            mid::ty::UintTy::U8 => NumKind::U8,
+            mid::ty::UintTy::U16 => NumKind::U16,
+            mid::ty::UintTy::U32 => NumKind::U32,
+            mid::ty::UintTy::U64 => NumKind::U64,
+            mid::ty::UintTy::U128 => NumKind::U128,
+        }
+    }
 }
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
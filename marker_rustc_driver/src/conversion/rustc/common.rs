@@ -2,8 +2,8 @@ use std::mem::{size_of, transmute};
 
 use marker_api::{
     ast::{
-        BodyId, CrateId, ExprId, FieldId, GenericId, ItemId, LetStmtId, Span, SpanId, StmtIdInner, SymbolId, TyDefId,
-        VarId, VariantId,
+        BodyId, CrateId, ExprId, FieldId, GenericId, ItemId, LetStmtId, Span, SpanId, SpanSrcId, StmtIdInner, SymbolId,
+        TyDefId, VarId, VariantId,
     },
     diagnostic::{Applicability, EmissionNode},
     lint::Level,
@@ -120,6 +120,11 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
         unsafe { transmute(api_id) }
     }
 
+    #[must_use]
+    pub fn to_syntax_context(&self, api_id: SpanSrcId) -> rustc_span::SyntaxContext {
+        transmute_id!(SpanSrcId as rustc_span::SyntaxContext = api_id)
+    }
+
     #[must_use]
     pub fn to_def_id(&self, api_id: impl Into<DefIdInfo>) -> hir::def_id::DefId {
         let info: DefIdInfo = api_id.into();
@@ -141,6 +146,9 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
             },
             EmissionNode::Field(id) => return Some(self.to_hir_id(id)),
             EmissionNode::Variant(id) => self.to_def_id(id),
+            EmissionNode::Crate(id) => {
+                return (self.to_crate_num(id) == hir::def_id::LOCAL_CRATE).then_some(hir::CRATE_HIR_ID);
+            },
             _ => unreachable!(),
         };
 
@@ -0,0 +1,237 @@
+use marker_api::ast::{
+    generic::SemGenericArgKind,
+    ty::{NumKind, SemTyKind, TextKind},
+    ConstValue, Mutability,
+};
+use rustc_hir as hir;
+use rustc_middle as mid;
+use rustc_middle::ty::Ty;
+
+use super::RustcConverter;
+
+impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
+    /// Converts the given semantic type back into a rustc type. Regions are erased,
+    /// since they are not represented by marker's semantic types.
+    ///
+    /// This returns `None`, if the type can't be represented, for example due to
+    /// missing information, like the value of const generics.
+    #[must_use]
+    pub fn to_ty(&self, api_ty: SemTyKind<'ast>) -> Option<Ty<'tcx>> {
+        let tcx = self.rustc_cx;
+        let ty = match api_ty {
+            SemTyKind::Bool(_) => tcx.types.bool,
+            SemTyKind::Num(num) => self.to_num_ty(num.numeric_kind()),
+            SemTyKind::Text(text) => match text.textual_kind() {
+                TextKind::Char => tcx.types.char,
+                TextKind::Str => tcx.types.str_,
+                _ => unreachable!(),
+            },
+            SemTyKind::Never(_) => tcx.types.never,
+            SemTyKind::Tuple(tuple) => {
+                let tys = tuple
+                    .types()
+                    .iter()
+                    .map(|ty| self.to_ty(*ty))
+                    .collect::<Option<Vec<_>>>()?;
+                Ty::new_tup(tcx, &tys)
+            },
+            SemTyKind::Slice(slice) => Ty::new_slice(tcx, self.to_ty(slice.inner_ty())?),
+            SemTyKind::Ref(reference) => Ty::new_ref(
+                tcx,
+                tcx.lifetimes.re_erased,
+                mid::ty::TypeAndMut {
+                    ty: self.to_ty(reference.inner_ty())?,
+                    mutbl: self.to_mutability(reference.mutability()),
+                },
+            ),
+            SemTyKind::RawPtr(ptr) => Ty::new_ptr(
+                tcx,
+                mid::ty::TypeAndMut {
+                    ty: self.to_ty(ptr.inner_ty())?,
+                    mutbl: self.to_mutability(ptr.mutability()),
+                },
+            ),
+            SemTyKind::Adt(adt) => {
+                let def_id = self.to_def_id(adt.def_id());
+                if !matches!(
+                    tcx.def_kind(def_id),
+                    hir::def::DefKind::Struct | hir::def::DefKind::Union | hir::def::DefKind::Enum
+                ) {
+                    return None;
+                }
+                Ty::new_adt(
+                    tcx,
+                    tcx.adt_def(def_id),
+                    self.to_substs(def_id, adt.generics().args(), None)?,
+                )
+            },
+            SemTyKind::FnTy(fn_ty) => {
+                let def_id = self.to_def_id(fn_ty.fn_id());
+                Ty::new_fn_def(tcx, def_id, self.to_substs(def_id, fn_ty.generics().args(), None)?)
+            },
+            SemTyKind::Generic(generic) => {
+                let def_id = self.to_def_id(generic.generic_id());
+                let index = tcx.generics_of(tcx.parent(def_id)).param_def_id_to_index(tcx, def_id)?;
+                Ty::new_param(tcx, index, tcx.item_name(def_id))
+            },
+            // Arrays with lengths, which depend on generic parameters, can't be
+            // converted, since the length is not represented as a constant.
+            SemTyKind::Array(array) => match array.len() {
+                ConstValue::Int(len) => {
+                    Ty::new_array(tcx, self.to_ty(array.inner_ty())?, len.to_u128()?.try_into().ok()?)
+                },
+                #[expect(non_exhaustive_omitted_patterns)]
+                _ => return None,
+            },
+            // Closure signatures are not yet available in marker's semantic types.
+            // Trait objects and aliases would require the conversion of bounds
+            // and projections.
+            SemTyKind::ClosureTy(_)
+            | SemTyKind::FnPtr(_)
+            | SemTyKind::TraitObj(_)
+            | SemTyKind::Alias(_)
+            | SemTyKind::Unstable(_) => return None,
+            _ => unreachable!(),
+        };
+        Some(ty)
+    }
+
+    /// Creates the generic arguments for the item with the given [`DefId`](hir::def_id::DefId).
+    /// Lifetimes are erased and omitted type arguments are filled with the defaults of
+    /// the parameters. The `self_ty` is used for the `Self` parameter of traits.
+    ///
+    /// This returns `None`, if the given arguments don't match the parameters of the
+    /// item.
+    #[must_use]
+    pub fn to_substs(
+        &self,
+        def_id: hir::def_id::DefId,
+        api_args: &[SemGenericArgKind<'ast>],
+        self_ty: Option<Ty<'tcx>>,
+    ) -> Option<mid::ty::SubstsRef<'tcx>> {
+        let tcx = self.rustc_cx;
+        let has_self = tcx.generics_of(def_id).has_self;
+        let mut args = api_args.iter();
+        let mut valid = true;
+        let substs = mid::ty::InternalSubsts::for_item(tcx, def_id, |param, substs| {
+            match &param.kind {
+                mid::ty::GenericParamDefKind::Lifetime => return tcx.lifetimes.re_erased.into(),
+                mid::ty::GenericParamDefKind::Type { .. } if has_self && param.index == 0 => {
+                    if let Some(self_ty) = self_ty {
+                        return self_ty.into();
+                    }
+                },
+                mid::ty::GenericParamDefKind::Type { .. } | mid::ty::GenericParamDefKind::Const { .. } => {},
+            }
+
+            match args.next() {
+                Some(SemGenericArgKind::Ty(ty)) if matches!(param.kind, mid::ty::GenericParamDefKind::Type { .. }) => {
+                    if let Some(ty) = self.to_ty(*ty) {
+                        return ty.into();
+                    }
+                },
+                None => {
+                    if let Some(default) = param.default_value(tcx) {
+                        return default.subst(tcx, substs);
+                    }
+                },
+                Some(_) => {},
+            }
+
+            // The parameter itself is used as a placeholder, the created substs
+            // are discarded anyways.
+            valid = false;
+            tcx.mk_param_from_def(param)
+        });
+
+        (valid && args.next().is_none()).then_some(substs)
+    }
+
+    /// Returns the [`ParamEnv`](mid::ty::ParamEnv) of the item, that declares the
+    /// generic parameters used by the given types. An empty environment is returned,
+    /// if the types don't contain any generic parameters.
+    #[must_use]
+    pub fn to_param_env(&self, api_tys: &[SemTyKind<'ast>]) -> mid::ty::ParamEnv<'tcx> {
+        let mut owner = None;
+        for api_ty in api_tys {
+            self.find_generics_owner(*api_ty, &mut owner);
+        }
+        owner.map_or_else(mid::ty::ParamEnv::empty, |owner| self.rustc_cx.param_env(owner))
+    }
+
+    /// Generic parameters of the same type can be declared by different items, like
+    /// an `impl` block and a method inside it. In that case, the inner item also
+    /// inherits the parameters of the outer item. The owner with the most parameters
+    /// is therefore selected.
+    fn find_generics_owner(&self, api_ty: SemTyKind<'ast>, owner: &mut Option<hir::def_id::DefId>) {
+        let tcx = self.rustc_cx;
+        let find_in_args = |args: &[SemGenericArgKind<'ast>], owner: &mut Option<hir::def_id::DefId>| {
+            for arg in args {
+                if let SemGenericArgKind::Ty(ty) = arg {
+                    self.find_generics_owner(*ty, owner);
+                }
+            }
+        };
+        match api_ty {
+            SemTyKind::Tuple(tuple) => {
+                for ty in tuple.types() {
+                    self.find_generics_owner(*ty, owner);
+                }
+            },
+            SemTyKind::Slice(slice) => self.find_generics_owner(slice.inner_ty(), owner),
+            SemTyKind::Array(array) => self.find_generics_owner(array.inner_ty(), owner),
+            SemTyKind::Ref(reference) => self.find_generics_owner(reference.inner_ty(), owner),
+            SemTyKind::RawPtr(ptr) => self.find_generics_owner(ptr.inner_ty(), owner),
+            SemTyKind::Adt(adt) => find_in_args(adt.generics().args(), owner),
+            SemTyKind::FnTy(fn_ty) => find_in_args(fn_ty.generics().args(), owner),
+            SemTyKind::Generic(generic) => {
+                let parent = tcx.parent(self.to_def_id(generic.generic_id()));
+                let count = |def_id| tcx.generics_of(def_id).count();
+                if owner.map_or(true, |owner| count(owner) < count(parent)) {
+                    *owner = Some(parent);
+                }
+            },
+            SemTyKind::Bool(_)
+            | SemTyKind::Num(_)
+            | SemTyKind::Text(_)
+            | SemTyKind::Never(_)
+            | SemTyKind::ClosureTy(_)
+            | SemTyKind::FnPtr(_)
+            | SemTyKind::TraitObj(_)
+            | SemTyKind::Alias(_)
+            | SemTyKind::Unstable(_) => {},
+            _ => unreachable!(),
+        }
+    }
+
+    #[must_use]
+    fn to_num_ty(&self, kind: NumKind) -> Ty<'tcx> {
+        let types = &self.rustc_cx.types;
+        match kind {
+            NumKind::Isize => types.isize,
+            NumKind::I8 => types.i8,
+            NumKind::I16 => types.i16,
+            NumKind::I32 => types.i32,
+            NumKind::I64 => types.i64,
+            NumKind::I128 => types.i128,
+            NumKind::Usize => types.usize,
+            NumKind::U8 => types.u8,
+            NumKind::U16 => types.u16,
+            NumKind::U32 => types.u32,
+            NumKind::U64 => types.u64,
+            NumKind::U128 => types.u128,
+            NumKind::F32 => types.f32,
+            NumKind::F64 => types.f64,
+            _ => unreachable!(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_mutability(&self, api_mut: Mutability) -> rustc_ast::Mutability {
+        match api_mut {
+            Mutability::Mut => rustc_ast::Mutability::Mut,
+            Mutability::Unmut => rustc_ast::Mutability::Not,
+            _ => unreachable!(),
+        }
+    }
+}
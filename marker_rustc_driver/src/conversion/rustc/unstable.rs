@@ -19,10 +19,12 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
             // This extra value, with the explicit lifetime is needed to make rustc
             // see that it actually has the `'static` lifetime
             let lint: &'static rustc_lint::Lint = lints.borrow_mut().entry(api_lint).or_insert_with(move || {
-                // Not extracted to an extra function, as it's very specific
+                // Not extracted to an extra function, as it's very specific.
+                // The finer settings are enforced by `AstContext::emit_lint`, rustc
+                // should therefore not filter the emissions.
                 let report_in_external_macro = match api_lint.report_in_macro {
                     MacroReport::No => false,
-                    MacroReport::All => true,
+                    MacroReport::All | MacroReport::Local | MacroReport::Crate(_) => true,
                     _ => unreachable!(),
                 };
 
@@ -16,6 +16,7 @@
 #![allow(clippy::too_many_lines, reason = "long functions are unavoidable for matches")]
 
 extern crate rustc_ast;
+extern crate rustc_ast_pretty;
 extern crate rustc_data_structures;
 extern crate rustc_driver;
 extern crate rustc_errors;
@@ -29,6 +30,7 @@ extern crate rustc_middle;
 extern crate rustc_session;
 extern crate rustc_span;
 extern crate rustc_target;
+extern crate rustc_trait_selection;
 
 pub mod context;
 pub mod conversion;
@@ -39,7 +41,7 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::process::{exit, Command};
 
-use marker_adapter::{LintCrateInfo, LINT_CRATES_ENV};
+use marker_adapter::{Adapter, AdapterError, LintCrateInfo, LINT_CRATES_ENV};
 use rustc_session::config::ErrorOutputType;
 use rustc_session::EarlyErrorHandler;
 
@@ -78,11 +80,13 @@ impl rustc_driver::Callbacks for MarkerCallback {
         // code is executed.
         assert!(config.register_lints.is_none());
         let lint_crates = std::mem::take(&mut self.lint_crates);
-        config.register_lints = Some(Box::new(move |_sess, lint_store| {
+        config.register_lints = Some(Box::new(move |sess, lint_store| {
             // It looks like it can happen, that the `config` function is called
             // with a different thread than the actual lint pass later, how interesting.
             // This will not make sure that the adapter is always initiated.
-            lint_pass::RustcLintPass::init_adapter(&lint_crates).unwrap();
+            if let Err(err) = lint_pass::RustcLintPass::init_adapter(&lint_crates) {
+                sess.fatal(err.to_string());
+            }
             // Register lints from lint crates. This is required to have rustc track
             // the lint level correctly.
             let lints: Vec<_> = lint_pass::RustcLintPass::marker_lints()
@@ -203,6 +207,7 @@ Common options:
         --rustc              Pass all arguments to rustc
     -V, --version            Print version information and exit
         --toolchain          Print the required toolchain and API version
+        --lint-crates        Print the metadata of the lint crates, which will be loaded
 
 ---
 
@@ -211,17 +216,51 @@ interfacing with the driver directly and use `cargo marker` instead.
 "
     );
 }
+/// Loads the lint crates specified by the [`LINT_CRATES_ENV`] environment value
+/// and prints their metadata.
+fn print_lint_crates() {
+    let lint_crates = match LintCrateInfo::list_from_env() {
+        Ok(lint_crates) => lint_crates,
+        Err(AdapterError::LintCratesEnvUnset) => vec![],
+        Err(err) => panic!("Error while determining the lint crates to load: {err:#?}"),
+    };
+    let adapter = match Adapter::new(&lint_crates) {
+        Ok(adapter) => adapter,
+        Err(err) => {
+            eprintln!("error: {err}");
+            exit(1);
+        },
+    };
+
+    for krate in adapter.lint_crate_metadata() {
+        println!("lint-crate: {}", krate.name);
+        let fields = [
+            ("version", krate.version),
+            ("description", krate.description),
+            ("homepage", krate.homepage),
+            ("repository", krate.repository),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                println!("    {key}: {value}");
+            }
+        }
+        if !krate.required_api_features.is_empty() {
+            println!("    required-api-features: {}", krate.required_api_features.join(", "));
+        }
+        println!("    lints: {}", krate.lints.join(", "));
+    }
+}
+
 const BUG_REPORT_URL: &str = "https://github.com/rust-marker/marker/issues/new?template=panic.yml";
 
 fn main() {
     let handler = EarlyErrorHandler::new(ErrorOutputType::default());
     rustc_driver::init_rustc_env_logger(&handler);
 
-    // FIXME(xFrednet): The ICE hook would ideally distinguish where the error
-    // happens. Panics from lint crates should probably not terminate Marker
-    // completely, but instead warn the user and continue linting with the other
-    // lint crate. It would also be cool if the ICE hook printed the node that
-    // caused the panic in the lint crate. rust-marker/marker#10
+    // Panics in lint crates are caught by the lint crate itself and reported by
+    // the adapter. The lint crate is then disabled, while the others continue
+    // linting. The ICE hook therefore only handles panics from the driver.
 
     rustc_driver::install_ice_hook(BUG_REPORT_URL, |handler| {
         handler.note_without_error(format!("{}", rustc_tools_util::get_version_info!()));
@@ -269,6 +308,11 @@ fn main() {
             exit(0);
         }
 
+        if orig_args.iter().any(|a| a == "--lint-crates") {
+            print_lint_crates();
+            exit(0);
+        }
+
         // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
         // We're invoking the compiler programmatically, so we'll ignore this.
         let wrapper_mode = orig_args.get(1).map(Path::new).and_then(Path::file_stem) == Some("rustc".as_ref());
@@ -299,7 +343,7 @@ fn main() {
         if enable_marker {
             let lint_crates = match LintCrateInfo::list_from_env() {
                 Ok(lint_crates) => lint_crates,
-                Err(marker_adapter::AdapterError::LintCratesEnvUnset) => vec![],
+                Err(AdapterError::LintCratesEnvUnset) => vec![],
                 Err(err) => panic!("Error while determining the lint crates to load: {err:#?}"),
             };
             let mut callback = MarkerCallback { env_vars, lint_crates };
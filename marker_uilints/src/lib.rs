@@ -3,17 +3,31 @@
 
 use marker_api::{
     ast::{
-        item::{EnumVariant, Field, StaticItem},
-        ty::SemTyKind,
-        Span,
+        attr::{AttrArgs, Attribute, NestedMetaItem},
+        expr::MethodSource,
+        generic::SemGenericArgKind,
+        item::{EnumVariant, Field, FnItem, SemItem, SemItemKind, StaticItem, Visibility},
+        pat::PatKind,
+        ty::{SemTyKind, SynTyKind},
+        AstPathTarget, Crate, ItemId, NodeKind, Safety, Span,
     },
     diagnostic::{Applicability, EmissionNode},
     prelude::*,
     LintPass, LintPassInfo, LintPassInfoBuilder,
 };
+use marker_utils::cfg::{run_analysis, Analysis, BasicBlock, BlockId, Cfg, CfgNode, Direction};
 
 #[derive(Default)]
-struct TestLintPass {}
+struct TestLintPass {
+    /// The id of the last checked item, used as the emission node for patterns
+    /// and types, as they don't have their own ids.
+    last_item: Option<ItemId>,
+    /// The number of items visited since the last `check_crate` call.
+    visited_items: Option<usize>,
+    crate_hooks_item: Option<ItemId>,
+    /// The number of `unsafe` blocks surrounding the currently checked node.
+    unsafe_depth: usize,
+}
 
 marker_api::export_lint_pass!(TestLintPass);
 
@@ -36,6 +50,26 @@ marker_api::declare_lint! {
     marker_api::lint::MacroReport::All,
 }
 
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint used for marker's uitests.
+    ///
+    /// It's used to test lint emissions in local macros
+    LOCAL_MACRO_LINT,
+    Warn,
+    marker_api::lint::MacroReport::Local,
+}
+
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint used for marker's uitests.
+    ///
+    /// It's used to test lint emissions in macros from `alloc`
+    ALLOC_MACRO_LINT,
+    Warn,
+    marker_api::lint::MacroReport::for_crate("alloc"),
+}
+
 marker_api::declare_lint! {
     /// # What it does
     /// A lint used for markers uitests.
@@ -57,16 +91,57 @@ fn emit_item_with_test_name_lint<'ast>(
 
 impl LintPass for TestLintPass {
     fn info(&self) -> LintPassInfo {
-        LintPassInfoBuilder::new(Box::new([TEST_LINT, ITEM_WITH_TEST_NAME])).build()
+        LintPassInfoBuilder::new(Box::new([
+            TEST_LINT,
+            ITEM_WITH_TEST_NAME,
+            LOCAL_MACRO_LINT,
+            ALLOC_MACRO_LINT,
+        ]))
+        .crate_name(env!("CARGO_PKG_NAME"))
+        .version(env!("CARGO_PKG_VERSION"))
+        .build()
+    }
+
+    fn check_crate<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _krate: &'ast Crate<'ast>) {
+        self.visited_items = Some(0);
+    }
+
+    fn check_crate_post<'ast>(&mut self, cx: &'ast AstContext<'ast>, krate: &'ast Crate<'ast>) {
+        let Some(item_id) = self.crate_hooks_item else {
+            return;
+        };
+        let item = cx.item(item_id).unwrap();
+        cx.emit_lint(TEST_LINT, item_id, "crate checked", item.span(), |diag| {
+            diag.note(format!("Root items: {}", krate.items().len()));
+            diag.note(format!("Visited items: {:?}", self.visited_items));
+        });
     }
 
     fn check_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+        self.last_item = Some(item.id());
+        if let Some(count) = &mut self.visited_items {
+            *count += 1;
+        }
+        if matches!(item.ident(), Some(ident) if ident.name() == "check_crate_hooks") {
+            self.crate_hooks_item = Some(item.id());
+        }
+
         if let ItemKind::Fn(item) = item {
             if let Some(ident) = item.ident() {
                 if ident.name() == "test_ty_id_resolution_trigger" {
                     test_ty_id_resolution(cx);
                 } else if ident.name() == "uilints_please_ice_on_this" {
                     panic!("free ice cream for everyone!!!");
+                } else if ident.name().starts_with("cfg_") || ident.name().starts_with("resource_") {
+                    check_cfg_fn(cx, item);
+                } else if ident.name().starts_with("lookup_params") {
+                    cx.emit_lint(TEST_LINT, item.id(), "print parameter types", ident.span(), |diag| {
+                        for param in item.params() {
+                            if let PatKind::Ident(pat) = param.pat() {
+                                diag.note(format!("`{}`: {:?}", pat.name(), pat.ty()));
+                            }
+                        }
+                    });
                 }
             }
         }
@@ -112,6 +187,9 @@ impl LintPass for TestLintPass {
             );
         }
 
+        print_item_attrs_and_vis(cx, item);
+        print_const_values(cx, item);
+
         if let ItemKind::Fn(func) = item {
             if matches!(
                 item.ident().map(marker_api::ast::Ident::name),
@@ -143,6 +221,35 @@ impl LintPass for TestLintPass {
         }
     }
 
+    fn check_pat<'ast>(&mut self, cx: &'ast AstContext<'ast>, pat: PatKind<'ast>) {
+        if let (PatKind::Ident(ident), Some(item_id)) = (pat, self.last_item) {
+            if ident.name().starts_with("_visit_pat") {
+                cx.emit_lint(TEST_LINT, item_id, "visited pattern", pat.span(), |_| {});
+            }
+        }
+    }
+
+    fn check_ty<'ast>(&mut self, cx: &'ast AstContext<'ast>, ty: SynTyKind<'ast>) {
+        if let (SynTyKind::Path(path_ty), Some(item_id)) = (ty, self.last_item) {
+            if matches!(path_ty.path().segments().last(), Some(seg) if seg.ident().name() == "VisitTy") {
+                cx.emit_lint(TEST_LINT, item_id, "visited type", ty.span(), |_| {});
+            }
+        }
+    }
+
+    fn check_expr<'ast>(&mut self, _cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) {
+        if matches!(expr, ExprKind::Block(block) if block.safety() == Safety::Unsafe) {
+            self.unsafe_depth += 1;
+        }
+    }
+
+    fn check_expr_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) {
+        if matches!(expr, ExprKind::Block(block) if block.safety() == Safety::Unsafe) {
+            self.unsafe_depth -= 1;
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
     fn check_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) {
         // I didn't realize that `let_chains` are still unstable. This makes the
         // code significantly less readable -.-
@@ -159,6 +266,194 @@ impl LintPass for TestLintPass {
                     diag.note(format!("Debug: {span:#?}"));
                     diag.note(format!("Snippet: {}", span.snippet_or("..")));
                 });
+            } else if ident.name().starts_with("_expn") {
+                cx.emit_lint(PRINT_SPAN_LINT, stmt.id(), "print expansion", stmt.span(), |diag| {
+                    for info in expr.span().macro_backtrace() {
+                        diag.note(format!(
+                            "{:?} `{}` (has def crate: {}), called at `{}`",
+                            info.kind(),
+                            info.name(),
+                            info.def_crate().is_some(),
+                            info.call_site().snippet_or("..")
+                        ));
+                    }
+                });
+            } else if ident.name().starts_with("_location") {
+                cx.emit_lint(PRINT_SPAN_LINT, stmt.id(), "print location", expr.span(), |diag| {
+                    if let Some(loc) = expr.span().file_location() {
+                        diag.note(format!("File: {}", loc.file()));
+                        diag.note(format!("Start: {:?}", loc.start()));
+                        diag.note(format!("End: {:?}", loc.end()));
+                        for line in loc.lines() {
+                            diag.note(format!("Line: `{line}`"));
+                        }
+                    }
+                });
+            } else if ident.name().starts_with("_multipart") {
+                if let ExprKind::BinaryOp(op) = expr {
+                    cx.emit_lint(TEST_LINT, stmt.id(), "multipart suggestion", expr.span(), |diag| {
+                        let (left, right) = (op.left(), op.right());
+                        let parts =
+                            [left.span(), right.span()].map(|span| (span, format!("({})", span.snippet_or(".."))));
+                        diag.multipart_suggestion("wrap operands", parts, Applicability::MachineApplicable);
+                    });
+                }
+            } else if ident.name().starts_with("_labels") {
+                if let ExprKind::BinaryOp(op) = expr {
+                    let (left, right) = (op.left(), op.right());
+                    cx.emit_lint(TEST_LINT, stmt.id(), "span labels", left.span(), |diag| {
+                        diag.add_primary_span(right.span())
+                            .span_label(left.span(), "first operand")
+                            .span_label(right.span(), "second operand")
+                            .span_label(stmt.span(), "the statement");
+                    });
+                }
+            } else if ident.name().starts_with("_unsafe_depth") {
+                let depth = self.unsafe_depth;
+                cx.emit_lint(TEST_LINT, stmt.id(), "unsafe depth", stmt.span(), |diag| {
+                    diag.note(format!("Depth: {depth}"));
+                });
+            } else if ident.name().starts_with("_macro_report") {
+                let span = expr.span();
+                cx.emit_lint(LOCAL_MACRO_LINT, stmt.id(), "local macro report", span, |_| {});
+                cx.emit_lint(ALLOC_MACRO_LINT, stmt.id(), "alloc macro report", span, |_| {});
+            } else if ident.name().starts_with("_implements") {
+                cx.emit_lint(
+                    TEST_LINT,
+                    stmt.id(),
+                    "check trait implementations",
+                    stmt.span(),
+                    |diag| {
+                        for note in fmt_trait_impls(cx, expr.ty()) {
+                            diag.note(note);
+                        }
+                    },
+                );
+            } else if ident.name().starts_with("_method") {
+                if let ExprKind::Method(call) = expr {
+                    cx.emit_lint(TEST_LINT, stmt.id(), "resolve method target", stmt.span(), |diag| {
+                        let target = call.resolve();
+                        diag.note(format!("Source: {:?}", target.source()));
+                        match target.source() {
+                            MethodSource::Impl(impl_id) => {
+                                if let Some(ItemKind::Impl(item)) = cx.item(impl_id) {
+                                    let found = item.items().iter().any(|assoc| assoc.id() == target.method());
+                                    diag.note(format!("Method found in local impl: {found}"));
+                                }
+                            },
+                            MethodSource::Trait(trait_id) => {
+                                let is_clone = cx.resolve_trait_ids("core::clone::Clone").contains(&trait_id);
+                                diag.note(format!("Is `Clone` trait: {is_clone}"));
+                            },
+                            _ => {},
+                        }
+                        diag.note(format!("Generics: {:#?}", target.generics().args()));
+                    });
+                }
+            } else if ident.name().starts_with("_resolve") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "check path resolution", stmt.span(), |diag| {
+                    for note in fmt_path_resolution(cx, expr) {
+                        diag.note(note);
+                    }
+                });
+            } else if ident.name().starts_with("_def_path") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print def paths", stmt.span(), |diag| {
+                    for note in fmt_def_paths(cx, expr) {
+                        diag.note(note);
+                    }
+                });
+            } else if ident.name().starts_with("_sem_item") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print semantic items", stmt.span(), |diag| {
+                    if let AstPathTarget::Item(id) = expr_target(expr) {
+                        if let Some(item) = cx.sem_item(id) {
+                            diag.note(format!("Target: {}", fmt_sem_item(item)));
+                        }
+                    }
+                    if let ExprKind::Method(call) = expr {
+                        let source = match call.resolve().source() {
+                            MethodSource::Impl(id) => cx.sem_item(id),
+                            MethodSource::Trait(id) => cx.sem_item(id),
+                            _ => unreachable!(),
+                        };
+                        if let Some(item) = source {
+                            diag.note(format!("Method source: {}", fmt_sem_item(item)));
+                        }
+                    }
+                    if let SemTyKind::Adt(adt) = expr.ty() {
+                        if let Some(item) = cx.sem_item(adt.def_id()) {
+                            diag.note(format!("Type: {}", fmt_sem_item(item)));
+                        }
+                    }
+                });
+            } else if ident.name().starts_with("_ancestors") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print ancestors", stmt.span(), |diag| {
+                    let ancestors: Vec<_> = cx.ancestors(expr.id()).map(fmt_node).collect();
+                    diag.note(format!("Expr ancestors: {}", ancestors.join(" -> ")));
+                    diag.note(format!("Stmt parent: {:?}", cx.parent(stmt.id()).map(fmt_node)));
+                    diag.note(format!(
+                        "Enclosing item: {:?}",
+                        cx.enclosing_item(expr.id()).map(|item| fmt_node(NodeKind::Item(item)))
+                    ));
+                    diag.note(format!(
+                        "Enclosing fn: {:?}",
+                        cx.enclosing_fn(expr.id())
+                            .and_then(|item| item.ident().map(marker_api::ast::Ident::name))
+                    ));
+                });
+            } else if ident.name().starts_with("_lookup") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "lookup nodes", stmt.span(), |diag| {
+                    let looked_up_expr = cx.expr(expr.id());
+                    diag.note(format!(
+                        "Expr: {:?} (same id: {})",
+                        looked_up_expr.map(|expr| fmt_node(NodeKind::Expr(expr))),
+                        looked_up_expr.map(|expr| expr.id()) == Some(expr.id())
+                    ));
+                    let looked_up_stmt = cx.stmt(stmt.id());
+                    diag.note(format!(
+                        "Stmt: {:?} (same id: {})",
+                        looked_up_stmt.map(|stmt| fmt_node(NodeKind::Stmt(stmt))),
+                        looked_up_stmt.map(|stmt| stmt.id()) == Some(stmt.id())
+                    ));
+                    if let AstPathTarget::Var(var_id) = expr_target(expr) {
+                        if let Some(var) = cx.var(var_id) {
+                            diag.note(format!(
+                                "Var: `{}` (mutability: {:?}, is_ref: {}) declared at `{}`",
+                                var.name(),
+                                var.mutability(),
+                                var.is_ref(),
+                                var.span().snippet_or("..")
+                            ));
+                            diag.note(format!("Var type: {:?}", var.ty()));
+                        }
+                    }
+                });
+            } else if ident.name().starts_with("_usage") {
+                let body = cx.ancestors(stmt.id()).find_map(|node| match node {
+                    NodeKind::Body(body) => Some(body),
+                    _ => None,
+                });
+                if let Some(body) = body {
+                    cx.emit_lint(TEST_LINT, stmt.id(), "print variable usages", ident.span(), |diag| {
+                        for usage in marker_utils::usage::var_usages(cx, body, ident.var_id()) {
+                            diag.span_note(format!("{:?}", usage.kind()), usage.span());
+                        }
+                    });
+                }
+            } else if ident.name().starts_with("_attrs") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print attributes", stmt.span(), |diag| {
+                    diag.note(format!("Stmt:{}", fmt_attrs(stmt.attrs())));
+                    diag.note(format!("Expr:{}", fmt_attrs(expr.attrs())));
+                });
+            } else if ident.name().starts_with("_eval") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print const values", stmt.span(), |diag| {
+                    if let Some(SynTyKind::Array(array)) = lets.ty() {
+                        let len = array.len().map(|len| cx.eval_const(len.body_id()));
+                        diag.note(format!("Syntactic length: {len:?}"));
+                    }
+                    if let SemTyKind::Array(array) = expr.ty() {
+                        diag.note(format!("Semantic length: {:?}", array.len()));
+                    }
+                });
             } else if ident.name().starts_with("_ty") {
                 cx.emit_lint(TEST_LINT, stmt.id(), "print type test", stmt.span(), |diag| {
                     diag.note(format!("{:#?}", expr.ty()));
@@ -189,6 +484,428 @@ impl LintPass for TestLintPass {
     }
 }
 
+fn print_item_attrs_and_vis<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    if matches!(
+        item.ident().map(marker_api::ast::Ident::name),
+        Some(name) if name.starts_with("PrintAttrs") || name.starts_with("print_attrs")
+    ) {
+        cx.emit_lint(
+            TEST_LINT,
+            item.id(),
+            "printing attributes",
+            item.ident().unwrap().span(),
+            |diag| {
+                diag.note(format!("Item:{}", fmt_attrs(item.attrs())));
+                if let ItemKind::Struct(strukt) = item {
+                    for field in strukt.fields() {
+                        diag.note(format!("Field `{}`:{}", field.ident(), fmt_attrs(field.attrs())));
+                    }
+                }
+                if let ItemKind::Enum(enum_item) = item {
+                    for variant in enum_item.variants() {
+                        diag.note(format!("Variant `{}`:{}", variant.ident(), fmt_attrs(variant.attrs())));
+                    }
+                }
+            },
+        );
+    }
+
+    if matches!(
+        item.ident().map(marker_api::ast::Ident::name),
+        Some(name) if name.starts_with("PrintVis") || name.starts_with("print_vis") || name.starts_with("PRINT_VIS")
+    ) {
+        cx.emit_lint(
+            TEST_LINT,
+            item.id(),
+            "printing visibility",
+            item.ident().unwrap().span(),
+            |diag| {
+                diag.note(format!("Item: {}", fmt_vis(item.visibility())));
+                if let ItemKind::Struct(strukt) = item {
+                    for field in strukt.fields() {
+                        diag.note(format!("Field `{}`: {}", field.ident(), fmt_vis(field.visibility())));
+                    }
+                }
+            },
+        );
+    }
+}
+
+fn fmt_vis(vis: &Visibility<'_>) -> String {
+    let snippet = vis
+        .span()
+        .map_or_else(|| "<none>".to_string(), |span| span.snippet_or(".."));
+    format!(
+        "{:?} (snippet: `{snippet}`, exported: {})",
+        vis.kind(),
+        vis.is_exported()
+    )
+}
+
+fn fmt_trait_impls<'ast>(cx: &'ast AstContext<'ast>, ty: SemTyKind<'ast>) -> Vec<String> {
+    let implements = |ty, path, args: &[SemGenericArgKind<'ast>]| {
+        cx.resolve_trait_ids(path)
+            .iter()
+            .any(|trait_id| cx.implements_trait(ty, *trait_id, args))
+    };
+
+    let mut notes: Vec<_> = ["core::marker::Copy", "core::clone::Clone", "core::default::Default"]
+        .into_iter()
+        .map(|path| format!("Implements `{path}`: {}", implements(ty, path, &[])))
+        .collect();
+    if let SemTyKind::Tuple(tuple) = ty {
+        if let [first, second] = tuple.types() {
+            let path = "core::cmp::PartialEq";
+            let res = implements(*first, path, &[SemGenericArgKind::Ty(*second)]);
+            notes.push(format!("First implements `{path}<Second>`: {res}"));
+        }
+    }
+    notes
+}
+
+fn fmt_path_resolution<'ast>(cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> Vec<String> {
+    const FN_PATHS: &[&str] = &[
+        "std::mem::forget",
+        "alloc::vec::Vec::new",
+        "core::clone::Clone::clone",
+        "alloc::string::String::len",
+        "crate::local_fn",
+        "crate::Local::new",
+    ];
+    const CONST_PATHS: &[&str] = &["crate::LOCAL_CONST", "crate::Local::ASSOC_CONST"];
+    const STATIC_PATHS: &[&str] = &["crate::LOCAL_STATIC"];
+    const VARIANT_PATHS: &[&str] = &["core::option::Option::Some", "core::option::Option::None"];
+
+    let mut notes = vec![];
+    match expr_target(expr) {
+        AstPathTarget::Item(id) => {
+            let resolved = FN_PATHS
+                .iter()
+                .filter(|path| cx.resolve_fn_ids(path).contains(&id))
+                .map(|path| ("fn", path))
+                .chain(
+                    CONST_PATHS
+                        .iter()
+                        .filter(|path| cx.resolve_const_ids(path).contains(&id))
+                        .map(|path| ("const", path)),
+                )
+                .chain(
+                    STATIC_PATHS
+                        .iter()
+                        .filter(|path| cx.resolve_static_ids(path).contains(&id))
+                        .map(|path| ("static", path)),
+                );
+            notes.extend(resolved.map(|(kind, path)| format!("Resolved {kind}: `{path}`")));
+        },
+        AstPathTarget::Variant(id) => {
+            for path in VARIANT_PATHS {
+                if cx.resolve_variant_ids(path).contains(&id) {
+                    notes.push(format!("Resolved variant: `{path}`"));
+                }
+            }
+        },
+        _ => {},
+    }
+    notes
+}
+
+fn fmt_def_paths<'ast>(cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> Vec<String> {
+    let mut notes = vec![];
+    match expr_target(expr) {
+        AstPathTarget::Item(id) => notes.push(format!("Target: `{}`", cx.def_path(id))),
+        AstPathTarget::Variant(id) => notes.push(format!("Target: `{}`", cx.def_path(id))),
+        _ => {},
+    }
+    if let ExprKind::Method(call) = expr {
+        let source = match call.resolve().source() {
+            MethodSource::Impl(id) => cx.def_path(id),
+            MethodSource::Trait(id) => cx.def_path(id),
+            _ => unreachable!(),
+        };
+        notes.push(format!("Method source: `{source}`"));
+    }
+    if let SemTyKind::Adt(adt) = expr.ty() {
+        notes.push(format!("Type: `{}`", cx.def_path(adt.def_id())));
+    }
+    notes
+}
+
+fn fmt_sem_item(item: &SemItem<'_>) -> String {
+    let generics: Vec<_> = item
+        .generics()
+        .iter()
+        .map(marker_api::ast::generic::SemGenericParam::name)
+        .collect();
+    let is_non_exhaustive_attr = item
+        .attrs()
+        .iter()
+        .any(|attr| attr.path().len() == 1 && attr.path()[0].name() == "non_exhaustive");
+    let details = match item.kind() {
+        SemItemKind::Fn(func) => format!(
+            "Fn(params: {}, has_self: {}, safety: {:?})",
+            func.params().len(),
+            func.has_self(),
+            func.safety()
+        ),
+        SemItemKind::Adt(adt) => {
+            let variants: Vec<_> = adt
+                .variants()
+                .iter()
+                .map(|variant| {
+                    let fields: Vec<_> = variant
+                        .fields()
+                        .iter()
+                        .map(|field| format!("{}: {:?}", field.name(), field.visibility()))
+                        .collect();
+                    format!("{} {{ {} }}", variant.name(), fields.join(", "))
+                })
+                .collect();
+            format!(
+                "{:?}(non_exhaustive: {}, variants: [{}])",
+                adt.adt_kind(),
+                adt.is_non_exhaustive(),
+                variants.join(", ")
+            )
+        },
+        SemItemKind::Trait(trait_item) => format!("Trait(items: {})", trait_item.items().len()),
+        SemItemKind::Impl(impl_item) => format!("Impl(items: {})", impl_item.items().len()),
+        other => format!("{other:?}"),
+    };
+    format!(
+        "{} {:?} generics: {generics:?}, non_exhaustive attr: {is_non_exhaustive_attr}, {details}",
+        item.name().unwrap_or("<unnamed>"),
+        item.visibility(),
+    )
+}
+
+fn fmt_node(node: NodeKind<'_>) -> String {
+    // The debug output starts with the name of the variant
+    let variant_name = |debug: String| debug.split(['(', ' ']).next().unwrap_or_default().to_string();
+    match node {
+        NodeKind::Item(item) => format!(
+            "Item({} {})",
+            variant_name(format!("{item:?}")),
+            item.ident().map_or("<unnamed>", |ident| ident.name())
+        ),
+        NodeKind::Body(_) => "Body".to_string(),
+        NodeKind::Stmt(stmt) => format!("Stmt({})", variant_name(format!("{stmt:?}"))),
+        NodeKind::Expr(expr) => format!("Expr({})", variant_name(format!("{expr:?}"))),
+        NodeKind::Pat(pat) => format!("Pat({})", variant_name(format!("{pat:?}"))),
+        NodeKind::Field(field) => format!("Field({})", field.ident()),
+        NodeKind::Variant(variant) => format!("Variant({})", variant.ident()),
+        _ => unreachable!(),
+    }
+}
+
+fn fmt_block(cfg: &Cfg<'_>, id: BlockId, block: &BasicBlock<'_>) -> String {
+    let nodes: Vec<_> = block
+        .nodes()
+        .iter()
+        .map(|node| match node {
+            CfgNode::Expr(expr @ (ExprKind::Call(_) | ExprKind::Method(_) | ExprKind::Path(_))) => {
+                expr.span().snippet_or("..").to_string()
+            },
+            CfgNode::Expr(expr) => fmt_node(NodeKind::Expr(*expr)),
+            CfgNode::Pat(pat) => format!("Pat({})", pat.span().snippet_or("..")),
+            _ => unreachable!(),
+        })
+        .collect();
+    let successors: Vec<_> = block
+        .successors()
+        .iter()
+        .map(|succ| format!("bb{}", succ.index()))
+        .collect();
+    format!(
+        "bb{}{}: [{}] -> [{}]",
+        id.index(),
+        if cfg.is_reachable(id) { "" } else { " (unreachable)" },
+        nodes.join(", "),
+        successors.join(", ")
+    )
+}
+
+fn print_const_values<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    let Some(ident) = item.ident() else {
+        return;
+    };
+    match item {
+        ItemKind::Const(item) if ident.name().starts_with("EVAL_") => {
+            if let Some(body_id) = item.body_id() {
+                cx.emit_lint(TEST_LINT, item.id(), "print const value", ident.span(), |diag| {
+                    diag.note(format!("{:?}", cx.eval_const(body_id)));
+                });
+            }
+        },
+        ItemKind::Enum(item) if ident.name().starts_with("Eval") => {
+            cx.emit_lint(TEST_LINT, item.id(), "print discriminants", ident.span(), |diag| {
+                for variant in item.variants() {
+                    let value = variant.discriminant().map(|expr| cx.eval_const(expr.body_id()));
+                    diag.note(format!("{}: {value:?}", variant.ident()));
+                }
+            });
+        },
+        _ => {},
+    }
+}
+
+fn check_cfg_fn<'ast>(cx: &'ast AstContext<'ast>, item: &'ast FnItem<'ast>) {
+    let (Some(ident), Some(body_id)) = (item.ident(), item.body_id()) else {
+        return;
+    };
+    let cfg = Cfg::new(cx.body(body_id));
+
+    if ident.name().starts_with("cfg_") {
+        cx.emit_lint(TEST_LINT, item.id(), "print control-flow graph", ident.span(), |diag| {
+            for (id, block) in cfg.blocks() {
+                diag.note(fmt_block(&cfg, id, block));
+            }
+        });
+    } else {
+        let held = run_analysis(&cfg, &MayHoldResource);
+        let logged = run_analysis(&cfg, &AlwaysLogs);
+        cx.emit_lint(TEST_LINT, item.id(), "run dataflow analyses", ident.span(), |diag| {
+            diag.note(format!(
+                "May hold resource at exit: {}",
+                held.state_at_entry(cfg.exit())
+            ));
+            diag.note(format!("Always logs: {}", logged.state_at_entry(cfg.entry())));
+        });
+    }
+}
+
+fn called_fn_name(node: CfgNode<'_>) -> Option<&str> {
+    let CfgNode::Expr(ExprKind::Call(call)) = node else {
+        return None;
+    };
+    let ExprKind::Path(path) = call.operand() else {
+        return None;
+    };
+    path.path().segments().last().map(|segment| segment.ident().name())
+}
+
+/// A forward analysis, checking if `acquire()` may have been called without a
+/// following `release()` call.
+struct MayHoldResource;
+
+impl<'ast> Analysis<'ast> for MayHoldResource {
+    type Domain = bool;
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> bool {
+        false
+    }
+
+    fn initial_state(&self) -> bool {
+        false
+    }
+
+    fn join(&self, state: &mut bool, other: &bool) {
+        *state |= *other;
+    }
+
+    fn transfer(&self, state: &mut bool, node: CfgNode<'ast>) {
+        match called_fn_name(node) {
+            Some("acquire") => *state = true,
+            Some("release") => *state = false,
+            _ => {},
+        }
+    }
+}
+
+/// A backward analysis, checking if `log()` is called on every path.
+struct AlwaysLogs;
+
+impl<'ast> Analysis<'ast> for AlwaysLogs {
+    type Domain = bool;
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn bottom(&self) -> bool {
+        true
+    }
+
+    fn initial_state(&self) -> bool {
+        false
+    }
+
+    fn join(&self, state: &mut bool, other: &bool) {
+        *state &= *other;
+    }
+
+    fn transfer(&self, state: &mut bool, node: CfgNode<'ast>) {
+        if called_fn_name(node) == Some("log") {
+            *state = true;
+        }
+    }
+}
+
+fn expr_target(expr: ExprKind<'_>) -> AstPathTarget {
+    match expr {
+        ExprKind::Call(call) => match call.operand() {
+            ExprKind::Path(path) => path.path().resolve(),
+            _ => AstPathTarget::Unresolved,
+        },
+        ExprKind::Path(path) => path.path().resolve(),
+        ExprKind::Ctor(ctor) => ctor.path().resolve(),
+        ExprKind::Method(call) => AstPathTarget::Item(call.resolve().method()),
+        _ => AstPathTarget::Unresolved,
+    }
+}
+
+fn fmt_attrs(attrs: &[Attribute<'_>]) -> String {
+    fn fmt_path(path: &[marker_api::ast::Ident<'_>]) -> String {
+        path.iter()
+            .map(marker_api::ast::Ident::name)
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+
+    fn fmt_meta_list(list: &[NestedMetaItem<'_>]) -> String {
+        let items: Vec<_> = list
+            .iter()
+            .map(|item| match item {
+                NestedMetaItem::Meta(meta) => {
+                    if let Some(list) = meta.list() {
+                        format!("{}({})", fmt_path(meta.path()), fmt_meta_list(list))
+                    } else if let Some(value) = meta.value() {
+                        format!("{} = {}", fmt_path(meta.path()), value.span().snippet_or(".."))
+                    } else {
+                        fmt_path(meta.path())
+                    }
+                },
+                NestedMetaItem::Lit(nested_lit) => nested_lit.span().snippet_or(".."),
+                _ => unreachable!(),
+            })
+            .collect();
+        items.join(", ")
+    }
+
+    let attrs: Vec<_> = attrs
+        .iter()
+        .map(|attr| {
+            let style = if attr.is_inner() { "inner" } else { "outer" };
+            let args = match attr.args() {
+                AttrArgs::Empty => String::new(),
+                AttrArgs::Delimited(args) => {
+                    if let Some(list) = args.meta() {
+                        format!(" meta: [{}]", fmt_meta_list(list))
+                    } else {
+                        format!(" tokens: `{}`", args.tokens())
+                    }
+                },
+                AttrArgs::Eq(lit) => format!(" value: {:?}", lit.str_value()),
+                _ => unreachable!(),
+            };
+            let doc = if attr.is_doc_comment() { " (doc comment)" } else { "" };
+            format!("\n- `{}` {style}{doc}{args}", fmt_path(attr.path()))
+        })
+        .collect();
+    if attrs.is_empty() {
+        " no attributes".to_string()
+    } else {
+        attrs.concat()
+    }
+}
+
 fn check_static_item<'ast>(cx: &'ast AstContext<'ast>, item: &'ast StaticItem<'ast>) {
     if let Some(name) = item.ident() {
         let name = name.name();
@@ -0,0 +1,39 @@
+struct Local;
+
+impl Local {
+    fn method(&self) {
+        let _ancestors_method = 1;
+    }
+}
+
+trait Trait {
+    fn provided(&self) {
+        let _ancestors_trait = 2;
+    }
+}
+
+fn nested(cond: bool) {
+    if cond {
+        let _ancestors_if = 3;
+    }
+
+    let closure = || {
+        let _ancestors_closure = 4;
+    };
+    closure();
+
+    fn inner() {
+        let _ancestors_inner = 5;
+    }
+    inner();
+}
+
+mod module {
+    pub fn in_module() {
+        let _ancestors_module = 6;
+    }
+}
+
+fn main() {
+    let _ancestors_main = 7;
+}
@@ -0,0 +1,85 @@
+fn acquire() {}
+fn release() {}
+fn log() {}
+fn check() -> bool {
+    true
+}
+
+fn cfg_if_else(value: u32) -> u32 {
+    if value > 2 { value } else { 0 }
+}
+
+fn cfg_match(value: Option<u32>) -> u32 {
+    match value {
+        Some(x) if x > 5 => x,
+        Some(x) => x + 1,
+        None => 0,
+    }
+}
+
+fn cfg_loops(values: Vec<u32>) {
+    'outer: for value in values {
+        while check() {
+            if value == 3 {
+                continue 'outer;
+            }
+            break;
+        }
+    }
+    loop {
+        log();
+        break;
+    }
+}
+
+fn cfg_diverging(value: Option<u32>) -> Option<u32> {
+    let x = value?;
+    if x == 0 {
+        return None;
+    }
+    if check() && x > 2 {
+        panic!("oh no");
+        log();
+    }
+    let Some(y) = value else { return None };
+    let z = 'block: {
+        if y > 4 {
+            break 'block 1;
+        }
+        2
+    };
+    Some(z)
+}
+
+fn resource_released() {
+    acquire();
+    if check() {
+        log();
+    } else {
+        log();
+    }
+    release();
+}
+
+fn resource_leaked_on_return(value: u32) {
+    acquire();
+    if value > 3 {
+        return;
+    }
+    log();
+    release();
+}
+
+fn resource_released_in_loop() {
+    loop {
+        acquire();
+        if check() {
+            release();
+            log();
+            break;
+        }
+        release();
+    }
+}
+
+fn main() {}
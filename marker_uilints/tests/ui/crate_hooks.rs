@@ -0,0 +1,9 @@
+mod module {
+    pub struct Inner;
+}
+
+fn check_crate_hooks() {
+    fn nested() {}
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+struct Local;
+
+impl Local {
+    fn new() -> Self {
+        Self
+    }
+}
+
+impl Clone for Local {
+    fn clone(&self) -> Self {
+        Self
+    }
+}
+
+mod nested {
+    pub mod inner {
+        pub fn nested_fn() {}
+    }
+}
+
+fn local_fn() {}
+
+fn main() {
+    let _def_path_local_fn = local_fn();
+    let _def_path_nested_fn = nested::inner::nested_fn();
+    let _def_path_local_new = Local::new();
+    let _def_path_local_clone = Local.clone();
+    let _def_path_forget = std::mem::forget(String::new());
+    let _def_path_len = String::new().len();
+    let _def_path_some = Some(4);
+    let _def_path_slice_len = [1, 2, 3].as_slice().len();
+}
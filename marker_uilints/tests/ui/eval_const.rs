@@ -0,0 +1,29 @@
+const EVAL_INT: u32 = 4 * 1024;
+const EVAL_NEG: i8 = -1;
+const EVAL_SIZE: usize = std::mem::size_of::<u64>() * 8;
+const EVAL_U128_MAX: u128 = u128::MAX;
+const EVAL_BOOL: bool = EVAL_INT > 1024;
+const EVAL_CHAR: char = 'x';
+const EVAL_STR: &str = "Hello World";
+const EVAL_BYTE_STR: &[u8; 5] = b"bytes";
+const EVAL_BYTE_SLICE: &[u8] = b"\xFFslice";
+const EVAL_FLOAT: f32 = 1.5;
+const EVAL_TUPLE: (u8, u8) = (1, 2);
+
+struct Wrapper<const N: usize>;
+
+impl<const N: usize> Wrapper<N> {
+    const EVAL_GENERIC: usize = N * 2;
+}
+
+#[repr(i16)]
+enum EvalDiscriminants {
+    A = -3,
+    B,
+    C = 1 << 4,
+}
+
+fn main() {
+    let _eval_arr: [u8; EVAL_INT as usize / 2] = [0; 2048];
+    let _eval_inferred = [0; 3];
+}
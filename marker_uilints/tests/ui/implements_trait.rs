@@ -0,0 +1,27 @@
+#[derive(Clone, Default)]
+struct Cloneable;
+
+struct Plain;
+
+fn generic<T: Clone>(t: T) {
+    let _implements_generic = t;
+}
+
+fn generic_array<T: Clone, const N: usize>(arr: [T; 3], generic_len: [T; N]) {
+    let _implements_generic_array = arr;
+    let _implements_generic_len_array = generic_len;
+}
+
+fn main() {
+    let _implements_int = 1;
+    let _implements_string = String::new();
+    let _implements_ref = &Plain;
+    let _implements_cloneable = Cloneable;
+    let _implements_plain = Plain;
+    let _implements_eq = (String::new(), "str");
+    let _implements_not_eq = (1_u8, 1_u16);
+    let _implements_array = [1_u8; 4];
+    let _implements_plain_array = [Plain, Plain];
+    generic(Cloneable);
+    generic_array([Cloneable, Cloneable, Cloneable], [Cloneable]);
+}
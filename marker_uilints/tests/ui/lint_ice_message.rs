@@ -2,11 +2,11 @@
 //@normalize-stderr-test: "lib.rs:.*" -> "lib.rs"
 
 // This function will trigger a panic in the `uilints` lint crate.
-// I want to see how the ICE message looks and make sure that it
-// links to the rust-marker/marker repo.
-//
-// Note: Panicking across crate bounds is unsound, that's a problem
-// we'll be ignoring for now rust-marker/marker#10
+// The panic should be reported, and the lint crate should be disabled
+// for the rest of the session, instead of terminating Marker.
 fn uilints_please_ice_on_this() {}
 
-fn main() {}
+fn main() {
+    // This would be linted, if the lint crate hadn't been disabled
+    let _print = 1;
+}
@@ -0,0 +1,18 @@
+//@compile-flags: -A marker::marker_uilints::item_with_test_name
+#![feature(register_tool)]
+#![register_tool(marker)]
+
+const FIND_ME_ALLOWED_BY_FLAG: i32 = 0;
+
+#[warn(marker::marker_uilints::item_with_test_name)]
+const FIND_ME_WARN: i32 = 0;
+
+#[warn(marker::marker_uilints::item_with_test_name)]
+mod warned {
+    const FIND_ME_INHERITED: i32 = 0;
+
+    #[allow(marker::marker_uilints::item_with_test_name)]
+    const FIND_ME_ALLOW: i32 = 0;
+}
+
+fn main() {}
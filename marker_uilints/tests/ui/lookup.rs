@@ -0,0 +1,43 @@
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let mut counter = 0u8;
+    counter += 1;
+    let _lookup_mut = counter;
+
+    let (ref first, second) = (String::new(), 1i32);
+    let _lookup_ref = first;
+    let _lookup_tuple = second;
+
+    match Some(2u16) {
+        Some(value @ 1..) | Some(value @ 0) => {
+            let _lookup_or = value;
+        },
+        None => {},
+    }
+
+    let Point { x, y: renamed } = Point { x: 1, y: 2 };
+    let _lookup_struct = x;
+    let _lookup_field = renamed;
+
+    let closure = |closure_param: u64| {
+        let _lookup_closure = closure_param;
+    };
+    closure(4);
+}
+
+fn with_params(param: &str, mut other: Vec<u8>) {
+    let _lookup_param = param;
+    let _lookup_mut_param = other;
+}
+
+trait Callbacks {
+    fn lookup_params_required(&self, required: u32, items: &[Self])
+    where
+        Self: Sized;
+
+    fn lookup_params_provided<T: Default>(&self, provided: Option<T>) {}
+}
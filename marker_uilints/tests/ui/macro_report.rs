@@ -0,0 +1,22 @@
+macro_rules! local_macro {
+    () => {
+        1
+    };
+}
+
+macro_rules! local_vec {
+    () => {
+        vec![1]
+    };
+}
+
+fn main() {
+    // Both lints should be emitted in normal code
+    let _macro_report_file = 1;
+    // Only the local lint should be emitted
+    let _macro_report_local = local_macro!();
+    // Only the alloc lint should be emitted, `vec!` is defined in `alloc`
+    let _macro_report_alloc = vec![1, 2];
+    // Neither lint should be emitted, since the backtrace contains local and alloc macros
+    let _macro_report_mixed = local_vec!();
+}
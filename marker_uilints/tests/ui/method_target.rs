@@ -0,0 +1,37 @@
+#[derive(Clone)]
+struct Sheep;
+
+impl Sheep {
+    fn shear<T>(&self, _amount: T) {}
+}
+
+trait Speak {
+    fn speak(&self) -> &'static str {
+        "baa"
+    }
+}
+
+impl Speak for Sheep {}
+
+struct Ram;
+
+impl Speak for Ram {
+    fn speak(&self) -> &'static str {
+        "BAA"
+    }
+}
+
+fn generic<T: Clone>(t: T) {
+    let _method_generic = t.clone();
+}
+
+fn main() {
+    let sheep = Sheep;
+    let _method_inherent = sheep.shear(1_u8);
+    let _method_trait = sheep.speak();
+    let _method_dependency = String::new().len();
+    let _method_dependency_trait = String::new().clone();
+    let _method_derived = sheep.clone();
+    let _method_trait_impl = Ram.speak();
+    generic(sheep);
+}
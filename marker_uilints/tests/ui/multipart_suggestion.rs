@@ -0,0 +1,6 @@
+fn main() {
+    let a = 1;
+    let b = 2;
+    let _multipart_simple = a + b;
+    let _multipart_nested = a * b - 3;
+}
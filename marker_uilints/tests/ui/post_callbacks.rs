@@ -0,0 +1,11 @@
+fn main() {
+    let _unsafe_depth_outside = 0;
+    unsafe {
+        let _unsafe_depth_inside = 1;
+        unsafe {
+            let _unsafe_depth_nested = 2;
+        }
+        let _unsafe_depth_after_nested = 1;
+    }
+    let _unsafe_depth_after = 0;
+}
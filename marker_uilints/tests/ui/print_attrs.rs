@@ -0,0 +1,38 @@
+#![feature(stmt_expr_attributes)]
+#![allow(dead_code)]
+
+/// A doc comment
+#[derive(Debug, Clone)]
+#[rustfmt::skip]
+struct PrintAttrsStruct {
+    #[allow(unused)]
+    field: u32,
+    /// Field doc
+    #[cfg_attr(all(), deprecated(note = "reason", since = "1.0.0"))]
+    other: u32,
+}
+
+#[repr(u8)]
+#[derive(PartialEq, core::fmt::Debug)]
+#[derive(Clone, Copy)]
+enum PrintAttrsEnum {
+    #[doc = "Variant doc"]
+    A = 1,
+    B,
+}
+
+#[inline(always)]
+#[must_use = "some reason"]
+fn print_attrs_fn() -> u32 {
+    #[allow(unused_parens)]
+    let _attrs = #[allow(unused_parens)]
+    (1 + 2);
+    0
+}
+
+mod print_attrs_mod {
+    //! Inner doc comment
+    #![allow(unused)]
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+//@aux-build:derive_nothing.rs:proc-macro
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate derive_nothing;
+
+#[derive(Nothing)]
+struct PrintAttrsNothing;
+
+#[derive(Debug, Nothing, Clone)]
+struct PrintAttrsMixed;
+
+fn main() {}
@@ -0,0 +1,27 @@
+macro_rules! inner {
+    () => {
+        1
+    };
+}
+
+macro_rules! outer {
+    () => {
+        inner!()
+    };
+}
+
+fn question_mark() -> Option<u32> {
+    let val = Some(1);
+    let _expn_question_mark = val?;
+    Some(0)
+}
+
+fn main() {
+    let _expn_file = 2;
+    let _expn_macro = inner!();
+    let _expn_nested = outer!();
+    let _expn_std = vec![1, 2];
+    let _ = question_mark();
+    let _expn_for = for _ in 0..1 {};
+    let _expn_async = async { 1 };
+}
@@ -0,0 +1,15 @@
+macro_rules! wrap {
+    ($e:expr) => {
+        $e
+    };
+}
+
+fn main() {
+    let _location_simple = 17;
+    let _location_unicode = ("äöü", "日本", 'x');
+    let _location_multi_line = [
+        1, 2,
+        3,
+    ];
+    let _location_macro = wrap!(1 + 2);
+}
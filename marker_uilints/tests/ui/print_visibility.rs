@@ -0,0 +1,43 @@
+#![allow(dead_code)]
+
+pub struct PrintVisStruct {
+    pub a: u32,
+    pub(crate) b: u32,
+    c: u32,
+}
+
+mod private {
+    pub fn print_vis_not_exported() {}
+    pub fn print_vis_reexported() {}
+    pub(crate) fn print_vis_crate() {}
+    pub(super) fn print_vis_super() {}
+    fn print_vis_inherited() {}
+
+    pub mod nested {
+        pub(in crate::private) fn print_vis_path() {}
+        pub(self) fn print_vis_self() {}
+        pub( super ) fn print_vis_spaced_super() {}
+
+        pub mod deeper {
+            pub(in crate::private) fn print_vis_deep_path() {}
+        }
+    }
+
+    macro_rules! super_fn {
+        ($name:ident) => {
+            pub(super) fn $name() {}
+        };
+    }
+
+    pub mod generated {
+        super_fn!(print_vis_generated_super);
+    }
+}
+
+pub use private::print_vis_reexported;
+
+pub static PRINT_VIS_STATIC: u32 = 0;
+
+pub(self) fn print_vis_root_self() {}
+
+fn main() {}
@@ -0,0 +1,38 @@
+#[non_exhaustive]
+pub struct Local {
+    pub field: u32,
+    private: u32,
+}
+
+impl Local {
+    pub fn new() -> Self {
+        Self { field: 1, private: 2 }
+    }
+
+    pub fn get(&self) -> u32 {
+        self.private
+    }
+}
+
+pub enum Choice<T> {
+    First(T),
+    #[non_exhaustive]
+    Second { value: T },
+}
+
+unsafe fn unsafe_fn() {}
+
+fn generic<T: Copy, const N: usize>(_value: [T; N]) {}
+
+fn main() {
+    let _sem_item_local = Local::new();
+    let _sem_item_method = Local::new().get();
+    let _sem_item_trait_method = String::new().clone();
+    let _sem_item_unsafe_fn = unsafe_fn;
+    let _sem_item_generic = generic([1, 2]);
+    let _sem_item_choice = Choice::First(1);
+    let _sem_item_int_error_kind = std::num::IntErrorKind::Empty;
+    let _sem_item_string = String::with_capacity(4);
+    let _sem_item_range = 1..2;
+    let _sem_item_forget = std::mem::forget(String::new());
+}
@@ -0,0 +1,33 @@
+#![feature(register_tool)]
+#![register_tool(marker)]
+#![allow(
+    marker::marker_uilints::test_lint,
+    marker::marker_uilints::item_with_test_name,
+    marker::marker_uilints::local_macro_lint,
+    marker::marker_uilints::alloc_macro_lint
+)]
+
+// All lints of the lint crate are allowed, it's therefore skipped
+const FIND_ME_SKIPPED: i32 = 0;
+
+#[warn(marker::marker_uilints::item_with_test_name)]
+mod reactivated {
+    // The lint is enabled again by the attribute of the module
+    const FIND_ME_REACTIVATED: i32 = 0;
+
+    #[allow(marker::marker_uilints::item_with_test_name)]
+    const FIND_ME_ALLOWED_AGAIN: i32 = 0;
+
+    mod nested {
+        const FIND_ME_NESTED: i32 = 0;
+    }
+}
+
+const FIND_ME_SKIPPED_AFTER_MODULE: i32 = 0;
+
+// The crate hooks are always called. The visited items only include the
+// items, for which the lint crate was active.
+#[warn(marker::marker_uilints::test_lint)]
+fn check_crate_hooks() {}
+
+fn main() {}
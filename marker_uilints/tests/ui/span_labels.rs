@@ -0,0 +1,7 @@
+fn main() {
+    let a = 1;
+    let b = 2;
+    let _labels_simple = a + b;
+    let _labels_multi_line = a
+        * b;
+}
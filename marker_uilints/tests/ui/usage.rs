@@ -0,0 +1,55 @@
+#[derive(Clone, Default)]
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+fn consume(_config: Config) {}
+
+fn main() {
+    let mut _usage_counter = 0;
+    _usage_counter += 1;
+    _usage_counter = 5;
+    let _copy = _usage_counter;
+    if _usage_counter > 3 {
+        println!("{_usage_counter}");
+    }
+    let borrowed = &mut _usage_counter;
+    *borrowed += 1;
+
+    let mut _usage_config = Config::default();
+    _usage_config.retries = 2;
+    let _retries = _usage_config.retries;
+    let _len = _usage_config.name.len();
+    _usage_config.name.push('x');
+    let _name = _usage_config.name.clone();
+    let cloned = _usage_config.clone();
+    consume(_usage_config);
+    drop(cloned);
+
+    let mut _usage_vec = vec![1, 2, 3];
+    _usage_vec[0] = 4;
+    let _first = _usage_vec[1];
+    let mut closure = || _usage_vec.push(5);
+    closure();
+    for _item in _usage_vec {}
+
+    let mut _usage_array = [1_u8, 2, 3];
+    let _array_copy = _usage_array;
+    _usage_array[0] = 4;
+    let _array_item = _usage_array[1];
+    drop(_usage_array);
+
+    let _usage_tuple = (1_u32, 'a');
+    let _tuple_copy = _usage_tuple;
+    let _tuple_field = _usage_tuple.0;
+    drop(_usage_tuple);
+
+    let _usage_string_tuple = (String::new(), 1_u32);
+    let _string_tuple_field = _usage_string_tuple.1;
+    let _moved_tuple = _usage_string_tuple;
+
+    let _usage_ref = &mut String::new();
+    _usage_ref.push('a');
+    let _moved = _usage_ref;
+}
@@ -0,0 +1,31 @@
+const LOCAL_CONST: u32 = 1;
+static LOCAL_STATIC: u32 = 2;
+
+struct Local;
+
+impl Local {
+    const ASSOC_CONST: u32 = 3;
+
+    fn new() -> Self {
+        Self
+    }
+}
+
+fn local_fn() {}
+
+fn main() {
+    let _resolve_forget = std::mem::forget(String::new());
+    let _resolve_core_forget = core::mem::forget(String::new());
+    let _resolve_vec_new: Vec<u32> = Vec::new();
+    let _resolve_clone = String::new().clone();
+    let _resolve_ufcs_clone = Clone::clone(&1);
+    let _resolve_len = String::new().len();
+    let _resolve_local_fn = local_fn();
+    let _resolve_local_new = Local::new();
+    let _resolve_const = LOCAL_CONST;
+    let _resolve_assoc_const = Local::ASSOC_CONST;
+    let _resolve_static = LOCAL_STATIC;
+    let _resolve_some = Some(4);
+    let _resolve_none: Option<u32> = None;
+    let _resolve_unrelated = Option::<u32>::is_some(&None);
+}
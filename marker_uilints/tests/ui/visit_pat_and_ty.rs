@@ -0,0 +1,46 @@
+struct VisitTy;
+
+impl VisitTy {
+    fn new() -> Self {
+        VisitTy
+    }
+}
+
+struct Wrapper<T>(T);
+
+struct Fields {
+    field: [Option<&'static VisitTy>; 1],
+}
+
+enum Variants {
+    Variant(Box<VisitTy>),
+}
+
+trait Trait<T> {}
+
+impl Trait<VisitTy> for Fields {}
+
+fn params(_visit_pat_param: (u8, *const VisitTy), (_visit_pat_tuple, _): (u8, u8)) -> Option<VisitTy> {
+    None
+}
+
+fn bounds<T>()
+where
+    T: Trait<VisitTy>,
+{
+}
+
+fn main() {
+    let _visit_pat_let: Wrapper<VisitTy> = Wrapper(VisitTy::new());
+    let ref _visit_pat_ref = 1;
+    let _fn_ptr: fn(VisitTy) -> VisitTy = |_visit_pat_closure: VisitTy| _visit_pat_closure;
+    let _ = Vec::<VisitTy>::new();
+    let _ = <VisitTy>::new();
+
+    match Some(1) {
+        Some(_visit_pat_match @ 1..=3) => {},
+        _ => {},
+    }
+    if let Some(_visit_pat_if_let) = Some(1) {}
+    for _visit_pat_for in 0..1 {}
+}
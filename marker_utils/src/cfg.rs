@@ -0,0 +1,509 @@
+//! This module contains a control-flow graph (CFG) for [`Body`]s and a
+//! generic dataflow framework, which can run analyses on the graph.
+//!
+//! ```ignore
+//! let cfg = Cfg::new(body);
+//! for (id, block) in cfg.blocks() {
+//!     if !cfg.is_reachable(id) && !block.nodes().is_empty() {
+//!         // This code is unreachable
+//!     }
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use marker_api::ast::{
+    expr::{BinaryOpKind, ExprKind},
+    item::Body,
+    pat::PatKind,
+    stmt::StmtKind,
+    ty::SemTyKind,
+    ExprId, Syncness,
+};
+
+mod dataflow;
+pub use dataflow::*;
+
+/// The id of a [`BasicBlock`] inside a [`Cfg`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlockId(usize);
+
+impl BlockId {
+    /// Returns the index of this block, which can be used to store information
+    /// about blocks in a [`Vec`]. The indices range from `0` to [`Cfg::len`].
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A node inside a [`BasicBlock`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum CfgNode<'ast> {
+    /// An evaluated expression. Expressions are added after their operands,
+    /// in the order in which they are evaluated. Closures and `async` blocks
+    /// are added as a single node, since their bodies are executed separately.
+    Expr(ExprKind<'ast>),
+    /// A pattern, which binds or assigns a value. This is added for `let`
+    /// statements and expressions, match arms, `for` loops and the assignee of
+    /// [`AssignExpr`](marker_api::ast::expr::AssignExpr)s. Patterns of `let`
+    /// expressions and `let`-`else` statements are added before the branch,
+    /// which depends on them.
+    Pat(PatKind<'ast>),
+}
+
+/// A sequence of [`CfgNode`]s, which are always executed in order. Branches
+/// are represented by multiple successors at the end of the block.
+#[derive(Debug)]
+pub struct BasicBlock<'ast> {
+    nodes: Vec<CfgNode<'ast>>,
+    successors: Vec<BlockId>,
+    predecessors: Vec<BlockId>,
+}
+
+impl<'ast> BasicBlock<'ast> {
+    #[must_use]
+    pub fn nodes(&self) -> &[CfgNode<'ast>] {
+        &self.nodes
+    }
+
+    /// Returns the blocks, which can be executed after this one. A block
+    /// without successors, other than the exit block of the [`Cfg`], diverges,
+    /// for example, by calling a function which returns `!`.
+    #[must_use]
+    pub fn successors(&self) -> &[BlockId] {
+        &self.successors
+    }
+
+    #[must_use]
+    pub fn predecessors(&self) -> &[BlockId] {
+        &self.predecessors
+    }
+}
+
+/// The control-flow graph of a [`Body`].
+///
+/// The graph starts at the [entry](Cfg::entry) block. All paths, which leave
+/// the body, end in the [exit](Cfg::exit) block. This includes returns, `?`
+/// operators and the final value of the body. The exit block has no nodes.
+///
+/// Code after a diverging expression, like `return` or a call to `panic!()`,
+/// is added to blocks without predecessors. These blocks are not
+/// [reachable](Cfg::is_reachable).
+///
+/// Calls and method calls, which return `!`, end their block without any
+/// successors. They are intentionally not connected to the exit block, since
+/// they never return to the caller. Panics and calls like `std::process::exit`
+/// are therefore not visible to analyses at the exit block. Expressions, which
+/// are unknown to this version of `marker_utils`, are added as a single node
+/// without any control-flow of their own, like unstable expressions.
+#[derive(Debug)]
+pub struct Cfg<'ast> {
+    blocks: Vec<BasicBlock<'ast>>,
+    reachable: Vec<bool>,
+}
+
+const ENTRY_BLOCK: BlockId = BlockId(0);
+const EXIT_BLOCK: BlockId = BlockId(1);
+
+impl<'ast> Cfg<'ast> {
+    /// Constructs the control-flow graph of the given [`Body`].
+    #[must_use]
+    pub fn new(body: &'ast Body<'ast>) -> Self {
+        let mut builder = CfgBuilder {
+            blocks: vec![],
+            current: ENTRY_BLOCK,
+            targets: HashMap::new(),
+        };
+        let entry = builder.new_block();
+        let exit = builder.new_block();
+        debug_assert_eq!((entry, exit), (ENTRY_BLOCK, EXIT_BLOCK));
+
+        builder.expr(body.expr());
+        builder.goto(EXIT_BLOCK);
+
+        let mut cfg = Self {
+            reachable: vec![false; builder.blocks.len()],
+            blocks: builder.blocks,
+        };
+        cfg.mark_reachable();
+        cfg
+    }
+
+    fn mark_reachable(&mut self) {
+        let mut stack = vec![ENTRY_BLOCK];
+        while let Some(id) = stack.pop() {
+            if !self.reachable[id.0] {
+                self.reachable[id.0] = true;
+                stack.extend_from_slice(&self.blocks[id.0].successors);
+            }
+        }
+    }
+
+    /// The block, which is executed first.
+    #[must_use]
+    pub fn entry(&self) -> BlockId {
+        ENTRY_BLOCK
+    }
+
+    /// The block, which all paths leaving the body end in.
+    #[must_use]
+    pub fn exit(&self) -> BlockId {
+        EXIT_BLOCK
+    }
+
+    /// Returns the number of blocks in this graph.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// A graph always contains at least the entry and exit block.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    #[must_use]
+    pub fn block(&self, id: BlockId) -> &BasicBlock<'ast> {
+        &self.blocks[id.0]
+    }
+
+    /// Returns an iterator over all blocks, ordered by their [`BlockId`].
+    pub fn blocks(&self) -> impl Iterator<Item = (BlockId, &BasicBlock<'ast>)> {
+        self.blocks
+            .iter()
+            .enumerate()
+            .map(|(index, block)| (BlockId(index), block))
+    }
+
+    /// Returns `true`, if the block can be reached from the entry block.
+    #[must_use]
+    pub fn is_reachable(&self, id: BlockId) -> bool {
+        self.reachable[id.0]
+    }
+}
+
+/// The blocks, which `break` and `continue` expressions jump to.
+#[derive(Debug, Clone, Copy)]
+struct JumpTarget {
+    break_block: BlockId,
+    continue_block: Option<BlockId>,
+}
+
+struct CfgBuilder<'ast> {
+    blocks: Vec<BasicBlock<'ast>>,
+    /// The block, which new nodes are added to.
+    current: BlockId,
+    /// The targets of loops and labeled blocks, identified by the [`ExprId`]
+    /// which is used as the `target_id` of `break` and `continue` expressions.
+    targets: HashMap<ExprId, JumpTarget>,
+}
+
+impl<'ast> CfgBuilder<'ast> {
+    fn new_block(&mut self) -> BlockId {
+        self.blocks.push(BasicBlock {
+            nodes: vec![],
+            successors: vec![],
+            predecessors: vec![],
+        });
+        BlockId(self.blocks.len() - 1)
+    }
+
+    fn add_edge(&mut self, from: BlockId, to: BlockId) {
+        if !self.blocks[from.0].successors.contains(&to) {
+            self.blocks[from.0].successors.push(to);
+            self.blocks[to.0].predecessors.push(from);
+        }
+    }
+
+    fn goto(&mut self, target: BlockId) {
+        self.add_edge(self.current, target);
+    }
+
+    /// Continues in a new block, which is connected to the current one.
+    fn goto_new(&mut self) -> BlockId {
+        let block = self.new_block();
+        self.goto(block);
+        self.current = block;
+        block
+    }
+
+    /// Continues in a new block without predecessors, after the current block
+    /// diverged.
+    fn diverge(&mut self) {
+        self.current = self.new_block();
+    }
+
+    fn push(&mut self, node: CfgNode<'ast>) {
+        self.blocks[self.current.0].nodes.push(node);
+    }
+
+    fn stmt(&mut self, stmt: StmtKind<'ast>) {
+        match stmt {
+            StmtKind::Let(lets) => {
+                if let Some(init) = lets.init() {
+                    self.expr(init);
+                }
+                self.push(CfgNode::Pat(lets.pat()));
+                if let Some(els) = lets.els() {
+                    let dispatch = self.current;
+                    self.goto_new();
+                    self.expr(els);
+                    // The `else` block has to diverge
+                    self.current = dispatch;
+                    self.goto_new();
+                }
+            },
+            StmtKind::Expr(expr, ..) => self.expr(*expr),
+            // Items are not executed as part of the body
+            _ => {},
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn expr(&mut self, expr: ExprKind<'ast>) {
+        match expr {
+            ExprKind::Block(block) if matches!(block.syncness(), Syncness::Async) => {},
+            ExprKind::Block(block) => {
+                let after = block.label().map(|_| {
+                    let after = self.new_block();
+                    self.targets.insert(
+                        expr.id(),
+                        JumpTarget {
+                            break_block: after,
+                            continue_block: None,
+                        },
+                    );
+                    after
+                });
+                for stmt in block.stmts() {
+                    self.stmt(*stmt);
+                }
+                if let Some(block_expr) = block.expr() {
+                    self.expr(block_expr);
+                }
+                if let Some(after) = after {
+                    self.goto(after);
+                    self.current = after;
+                }
+            },
+            ExprKind::If(e) => {
+                self.expr(e.condition());
+                let branch = self.current;
+                let join = self.new_block();
+
+                self.goto_new();
+                self.expr(e.then());
+                self.goto(join);
+
+                self.current = branch;
+                if let Some(els) = e.els() {
+                    self.goto_new();
+                    self.expr(els);
+                }
+                self.goto(join);
+                self.current = join;
+            },
+            ExprKind::Let(e) => {
+                self.expr(e.scrutinee());
+                self.push(CfgNode::Pat(e.pat()));
+            },
+            ExprKind::BinaryOp(e) if matches!(e.kind(), BinaryOpKind::And | BinaryOpKind::Or) => {
+                // The right operand is only evaluated, if the left one doesn't
+                // already determine the result.
+                self.expr(e.left());
+                let branch = self.current;
+                let join = self.new_block();
+
+                self.goto_new();
+                self.expr(e.right());
+                self.goto(join);
+
+                self.add_edge(branch, join);
+                self.current = join;
+            },
+            ExprKind::Match(e) => {
+                self.expr(e.scrutinee());
+                let dispatch = self.current;
+                let join = self.new_block();
+                let mut failed_guard = None;
+                for arm in e.arms() {
+                    let arm_block = self.new_block();
+                    self.add_edge(dispatch, arm_block);
+                    if let Some(failed_guard) = failed_guard.take() {
+                        self.add_edge(failed_guard, arm_block);
+                    }
+                    self.current = arm_block;
+                    self.push(CfgNode::Pat(arm.pat()));
+                    if let Some(guard) = arm.guard() {
+                        self.expr(guard);
+                        failed_guard = Some(self.current);
+                        self.goto_new();
+                    }
+                    self.expr(arm.expr());
+                    self.goto(join);
+                }
+                self.current = join;
+            },
+            ExprKind::Loop(e) => {
+                let header = self.goto_new();
+                let after = self.new_block();
+                self.targets.insert(
+                    expr.id(),
+                    JumpTarget {
+                        break_block: after,
+                        continue_block: Some(header),
+                    },
+                );
+                self.expr(e.block());
+                self.goto(header);
+                self.current = after;
+            },
+            ExprKind::While(e) => {
+                let header = self.goto_new();
+                let after = self.new_block();
+                self.targets.insert(
+                    expr.id(),
+                    JumpTarget {
+                        break_block: after,
+                        continue_block: Some(header),
+                    },
+                );
+                self.expr(e.condition());
+                self.goto(after);
+                self.goto_new();
+                self.expr(e.block());
+                self.goto(header);
+                self.current = after;
+            },
+            ExprKind::For(e) => {
+                self.expr(e.iterable());
+                let header = self.goto_new();
+                let after = self.new_block();
+                self.targets.insert(
+                    expr.id(),
+                    JumpTarget {
+                        break_block: after,
+                        continue_block: Some(header),
+                    },
+                );
+                self.goto(after);
+                self.goto_new();
+                self.push(CfgNode::Pat(e.pat()));
+                self.expr(e.block());
+                self.goto(header);
+                self.current = after;
+            },
+            ExprKind::Break(e) => {
+                if let Some(value) = e.expr() {
+                    self.expr(value);
+                }
+                self.push(CfgNode::Expr(expr));
+                if let Some(target) = self.targets.get(&e.target_id()) {
+                    self.goto(target.break_block);
+                }
+                self.diverge();
+                return;
+            },
+            ExprKind::Continue(e) => {
+                self.push(CfgNode::Expr(expr));
+                if let Some(continue_block) = self.targets.get(&e.target_id()).and_then(|t| t.continue_block) {
+                    self.goto(continue_block);
+                }
+                self.diverge();
+                return;
+            },
+            ExprKind::Return(e) => {
+                if let Some(value) = e.expr() {
+                    self.expr(value);
+                }
+                self.push(CfgNode::Expr(expr));
+                self.goto(EXIT_BLOCK);
+                self.diverge();
+                return;
+            },
+            ExprKind::QuestionMark(e) => {
+                self.expr(e.expr());
+                self.push(CfgNode::Expr(expr));
+                // The error case leaves the body
+                self.goto(EXIT_BLOCK);
+                self.goto_new();
+                return;
+            },
+            ExprKind::Call(e) => {
+                self.expr(e.operand());
+                for arg in e.args() {
+                    self.expr(*arg);
+                }
+                self.push(CfgNode::Expr(expr));
+                if matches!(expr.ty(), SemTyKind::Never(_)) {
+                    self.diverge();
+                }
+                return;
+            },
+            ExprKind::Method(e) => {
+                self.expr(e.receiver());
+                for arg in e.args() {
+                    self.expr(*arg);
+                }
+                self.push(CfgNode::Expr(expr));
+                if matches!(expr.ty(), SemTyKind::Never(_)) {
+                    self.diverge();
+                }
+                return;
+            },
+            ExprKind::Assign(e) => {
+                self.expr(e.value());
+                self.push(CfgNode::Pat(e.assignee()));
+            },
+            ExprKind::UnaryOp(e) => self.expr(e.expr()),
+            ExprKind::Ref(e) => self.expr(e.expr()),
+            ExprKind::BinaryOp(e) => {
+                self.expr(e.left());
+                self.expr(e.right());
+            },
+            ExprKind::As(e) => self.expr(e.expr()),
+            ExprKind::Array(e) => {
+                for element in e.elements() {
+                    self.expr(*element);
+                }
+            },
+            ExprKind::Tuple(e) => {
+                for element in e.elements() {
+                    self.expr(*element);
+                }
+            },
+            ExprKind::Ctor(e) => {
+                for field in e.fields() {
+                    self.expr(field.expr());
+                }
+                if let Some(base) = e.base() {
+                    self.expr(base);
+                }
+            },
+            ExprKind::Range(e) => {
+                if let Some(start) = e.start() {
+                    self.expr(start);
+                }
+                if let Some(end) = e.end() {
+                    self.expr(end);
+                }
+            },
+            ExprKind::Index(e) => {
+                self.expr(e.operand());
+                self.expr(e.index());
+            },
+            ExprKind::Field(e) => self.expr(e.operand()),
+            ExprKind::Await(e) => self.expr(e.expr()),
+            // Closures, paths, literals and unstable expressions have no operands,
+            // which are evaluated as part of this body. Expressions, which were
+            // added to the API after this graph was written, are treated as
+            // opaque nodes in the same way.
+            _ => {},
+        }
+
+        self.push(CfgNode::Expr(expr));
+    }
+}
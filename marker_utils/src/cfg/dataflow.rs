@@ -0,0 +1,175 @@
+use std::collections::VecDeque;
+
+use super::{BlockId, Cfg, CfgNode};
+
+/// The direction, in which a dataflow [`Analysis`] propagates its state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::exhaustive_enums)] // An analysis can only go forward or backward
+pub enum Direction {
+    /// The state flows from the entry block along the edges of the graph.
+    Forward,
+    /// The state flows from the exit block against the edges of the graph.
+    Backward,
+}
+
+/// A dataflow analysis, which can be run on a [`Cfg`] with [`run_analysis`].
+///
+/// The state of each block is computed from the states of its predecessors,
+/// or successors for backward analyses, using [`Analysis::join`]. The nodes of
+/// the block are then applied to the state with [`Analysis::transfer`]. This
+/// is repeated until the states no longer change. To ensure that this
+/// terminates, the domain should have a finite height and the transfer
+/// function should be monotonic.
+///
+/// ```ignore
+/// /// A forward analysis, which checks if `acquire()` was called without a
+/// /// following call to `release()`.
+/// struct MayHoldResource;
+///
+/// impl<'ast> Analysis<'ast> for MayHoldResource {
+///     type Domain = bool;
+///     const DIRECTION: Direction = Direction::Forward;
+///
+///     fn bottom(&self) -> bool {
+///         false
+///     }
+///
+///     fn initial_state(&self) -> bool {
+///         false
+///     }
+///
+///     fn join(&self, state: &mut bool, other: &bool) {
+///         *state |= *other;
+///     }
+///
+///     fn transfer(&self, state: &mut bool, node: CfgNode<'ast>) {
+///         match called_fn_name(node) {
+///             Some("acquire") => *state = true,
+///             Some("release") => *state = false,
+///             _ => {},
+///         }
+///     }
+/// }
+///
+/// let results = run_analysis(&cfg, &MayHoldResource);
+/// if *results.state_at_entry(cfg.exit()) {
+///     // The resource is not released on every path
+/// }
+/// ```
+pub trait Analysis<'ast> {
+    /// The state, which is tracked by this analysis.
+    type Domain: Clone + PartialEq;
+
+    /// The direction, in which the state is propagated.
+    const DIRECTION: Direction;
+
+    /// The state of blocks, which have not been reached by the analysis. This
+    /// should be the identity of [`Analysis::join`].
+    fn bottom(&self) -> Self::Domain;
+
+    /// The state at the start of the [entry](Cfg::entry) block for forward
+    /// analyses or at the end of the [exit](Cfg::exit) block for backward ones.
+    fn initial_state(&self) -> Self::Domain;
+
+    /// Combines the state of another path into the given state.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain);
+
+    /// Applies the effect of the given node to the state.
+    fn transfer(&self, state: &mut Self::Domain, node: CfgNode<'ast>);
+}
+
+/// The results of an [`Analysis`], which contain the state at the start and
+/// end of each block.
+#[derive(Debug)]
+pub struct DataflowResults<D> {
+    entry_states: Vec<D>,
+    exit_states: Vec<D>,
+}
+
+impl<D> DataflowResults<D> {
+    /// Returns the state before the first node of the given block. For backward
+    /// analyses, this is the state after all nodes of the block have been applied.
+    #[must_use]
+    pub fn state_at_entry(&self, block: BlockId) -> &D {
+        &self.entry_states[block.index()]
+    }
+
+    /// Returns the state after the last node of the given block. For backward
+    /// analyses, this is the state before the nodes of the block have been applied.
+    #[must_use]
+    pub fn state_at_exit(&self, block: BlockId) -> &D {
+        &self.exit_states[block.index()]
+    }
+}
+
+/// Runs the given [`Analysis`] on the [`Cfg`], until the states of all blocks
+/// no longer change.
+#[must_use]
+pub fn run_analysis<'ast, A: Analysis<'ast>>(cfg: &Cfg<'ast>, analysis: &A) -> DataflowResults<A::Domain> {
+    let mut results = DataflowResults {
+        entry_states: vec![analysis.bottom(); cfg.len()],
+        exit_states: vec![analysis.bottom(); cfg.len()],
+    };
+
+    // The state of every block is computed at least once
+    let mut worklist: VecDeque<BlockId> = cfg.blocks().map(|(id, _)| id).collect();
+    let mut in_worklist = vec![true; cfg.len()];
+    if A::DIRECTION == Direction::Backward {
+        worklist.make_contiguous().reverse();
+    }
+
+    while let Some(id) = worklist.pop_front() {
+        in_worklist[id.index()] = false;
+        let block = cfg.block(id);
+
+        let (changed, next) = match A::DIRECTION {
+            Direction::Forward => {
+                let mut state = if id == cfg.entry() {
+                    analysis.initial_state()
+                } else {
+                    analysis.bottom()
+                };
+                for pred in block.predecessors() {
+                    analysis.join(&mut state, results.state_at_exit(*pred));
+                }
+                results.entry_states[id.index()] = state.clone();
+
+                for node in block.nodes() {
+                    analysis.transfer(&mut state, *node);
+                }
+                let changed = results.exit_states[id.index()] != state;
+                results.exit_states[id.index()] = state;
+                (changed, block.successors())
+            },
+            Direction::Backward => {
+                let mut state = if id == cfg.exit() {
+                    analysis.initial_state()
+                } else {
+                    analysis.bottom()
+                };
+                for succ in block.successors() {
+                    analysis.join(&mut state, results.state_at_entry(*succ));
+                }
+                results.exit_states[id.index()] = state.clone();
+
+                for node in block.nodes().iter().rev() {
+                    analysis.transfer(&mut state, *node);
+                }
+                let changed = results.entry_states[id.index()] != state;
+                results.entry_states[id.index()] = state;
+                (changed, block.predecessors())
+            },
+        };
+
+        if changed {
+            for next_id in next {
+                if !in_worklist[next_id.index()] {
+                    in_worklist[next_id.index()] = true;
+                    worklist.push_back(*next_id);
+                }
+            }
+        }
+    }
+
+    results
+}
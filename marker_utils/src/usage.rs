@@ -0,0 +1,270 @@
+//! This module contains utilities to analyze how local variables are used.
+
+use std::{collections::HashMap, ops::ControlFlow};
+
+use marker_api::{
+    ast::{
+        expr::{BinaryOpKind, ExprKind, MethodExpr, UnaryOpKind},
+        item::{Body, SemItem, SemItemKind},
+        pat::PatKind,
+        ty::SemTyKind,
+        AstPathTarget, ExprId, Mutability, Span, TyDefId, VarId,
+    },
+    context::AstContext,
+};
+
+use crate::visitor::{traverse_body, Visitor};
+
+/// The way a variable is used by a [`VarUsage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UsageKind {
+    /// The value is read, without being moved. This includes copies of the
+    /// value and shared borrows, like `&x` or `x.len()`.
+    Read,
+    /// A new value is assigned to the variable or one of its fields, like
+    /// `x = 1`, `x.field = 2` or `x += 3`.
+    Write,
+    /// The variable is borrowed mutably, like `&mut x` or `x.push(1)`.
+    MutBorrow,
+    /// The value, or a part of it, is moved out of the variable, like `drop(x)`
+    /// or `let y = x.field`.
+    Move,
+}
+
+/// A single usage of a local variable, see [`var_usages`].
+#[derive(Debug, Clone, Copy)]
+pub struct VarUsage<'ast> {
+    kind: UsageKind,
+    expr: ExprKind<'ast>,
+}
+
+impl<'ast> VarUsage<'ast> {
+    /// Returns how the variable is used.
+    #[must_use]
+    pub fn kind(&self) -> UsageKind {
+        self.kind
+    }
+
+    /// Returns the path expression, which references the variable.
+    #[must_use]
+    pub fn expr(&self) -> ExprKind<'ast> {
+        self.expr
+    }
+
+    /// Returns the [`Span`] of the path expression, which references the variable.
+    #[must_use]
+    pub fn span(&self) -> &Span<'ast> {
+        self.expr.span()
+    }
+}
+
+/// Returns all usages of the variable with the given [`VarId`] in the given
+/// [`Body`], including usages inside closures. The usages are sorted in the
+/// order, in which they are visited by [`traverse_body`].
+///
+/// ```ignore
+/// let usages = var_usages(cx, body, var.var_id());
+/// if usages.iter().all(|usage| usage.kind() != UsageKind::Read) {
+///     // The variable is never read
+/// }
+/// ```
+///
+/// The usage is determined by the expression which contains the variable.
+/// Field and index expressions, like `x.field` or `x[0]`, are treated like the
+/// variable itself. A value used by-value is a [`UsageKind::Read`], if its type
+/// implements `Copy`, otherwise a [`UsageKind::Move`].
+///
+/// Some usages are approximated:
+/// * Scrutinees of `match` and `if let` expressions are always reported as [`UsageKind::Read`],
+///   even if a pattern moves a part of the value.
+/// * Variables captured by closures are reported with their usage inside the closure body.
+/// * Types, which can't be checked for `Copy` by the driver, are treated as not being `Copy`. See
+///   [`AstContext::implements_trait`] for more information.
+///
+/// # Panics
+///
+/// Panics, if the driver doesn't provide the expressions, which were visited
+/// in the given body. This indicates a bug in the driver.
+#[must_use]
+pub fn var_usages<'ast>(cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>, var: VarId) -> Vec<VarUsage<'ast>> {
+    let mut collector = UsageCollector {
+        var,
+        contexts: HashMap::new(),
+        copy_trait_ids: None,
+        usages: vec![],
+    };
+    let _: ControlFlow<()> = traverse_body(cx, &mut collector, body);
+
+    // The ids belong to expressions, which have been visited in this body.
+    // The driver therefore always provides them.
+    collector
+        .usages
+        .into_iter()
+        .map(|(kind, id)| {
+            let expr = cx.expr(id).expect("visited expressions are always available");
+            VarUsage { kind, expr }
+        })
+        .collect()
+}
+
+/// The context in which an expression is evaluated. This is set by the parent
+/// expression, before the child expressions are visited.
+#[derive(Debug, Clone, Copy)]
+enum UsageContext {
+    Kind(UsageKind),
+    /// The expression is used by-value. The type of the outermost place
+    /// expression decides, if the value is copied or moved. It's therefore
+    /// checked, when the place expression is visited.
+    Value,
+}
+
+/// The [`Visitor`] methods are generic over the `'ast` lifetime. The collector
+/// therefore only stores ids of the usages, which are converted to nodes afterwards.
+struct UsageCollector {
+    var: VarId,
+    contexts: HashMap<ExprId, UsageContext>,
+    copy_trait_ids: Option<Vec<TyDefId>>,
+    usages: Vec<(UsageKind, ExprId)>,
+}
+
+impl UsageCollector {
+    fn set_context(&mut self, expr: ExprKind<'_>, context: UsageContext) {
+        self.contexts.insert(expr.id(), context);
+    }
+
+    /// Returns `true`, if the given place expression, like `x`, `x.field` or
+    /// `x[0]`, is based on the tracked variable.
+    fn is_var_place(&self, mut expr: ExprKind<'_>) -> bool {
+        loop {
+            match expr {
+                ExprKind::Field(field) => expr = field.operand(),
+                ExprKind::Index(index) => expr = index.operand(),
+                ExprKind::Path(path) => {
+                    return matches!(path.path().resolve(), AstPathTarget::Var(id) if id == self.var);
+                },
+                _ => return false,
+            }
+        }
+    }
+
+    /// Determines the [`UsageKind`] of the given place expression in the given
+    /// context. By-value usages are copies, if the type of the place implements
+    /// `Copy`.
+    fn usage_kind<'ast>(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        place: ExprKind<'ast>,
+        context: UsageContext,
+    ) -> UsageKind {
+        if let UsageContext::Kind(kind) = context {
+            return kind;
+        }
+
+        let copy_trait_ids = self
+            .copy_trait_ids
+            .get_or_insert_with(|| cx.resolve_trait_ids("core::marker::Copy").to_vec());
+        let is_copy = copy_trait_ids
+            .iter()
+            .any(|trait_id| cx.implements_trait(place.ty(), *trait_id, &[]));
+
+        if is_copy { UsageKind::Read } else { UsageKind::Move }
+    }
+
+    /// Passes the context of a field or index expression on to its operand.
+    /// The usage kind is determined here, since the type of the outer place
+    /// decides, if a by-value usage copies or moves the value.
+    fn set_place_context<'ast>(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        place: ExprKind<'ast>,
+        operand: ExprKind<'ast>,
+        context: UsageContext,
+    ) {
+        let context = if self.is_var_place(place) {
+            UsageContext::Kind(self.usage_kind(cx, place, context))
+        } else {
+            context
+        };
+        self.set_context(operand, context);
+    }
+
+    /// Method calls automatically borrow the receiver, depending on the `self`
+    /// parameter of the method.
+    fn receiver_context<'ast>(&self, cx: &'ast AstContext<'ast>, call: &'ast MethodExpr<'ast>) -> UsageContext {
+        let receiver = call.receiver();
+        let Some(SemItemKind::Fn(method)) = cx.sem_item(call.resolve().method()).map(SemItem::kind) else {
+            return UsageContext::Value;
+        };
+        let Some(SemTyKind::Ref(self_ty)) = method.params().first().filter(|_| method.has_self()) else {
+            return UsageContext::Value;
+        };
+
+        // A receiver, which is already a reference, is dereferenced and
+        // borrowed again. The variable itself is only read.
+        if matches!(self_ty.mutability(), Mutability::Mut) && !matches!(receiver.ty(), SemTyKind::Ref(_)) {
+            UsageContext::Kind(UsageKind::MutBorrow)
+        } else {
+            UsageContext::Kind(UsageKind::Read)
+        }
+    }
+}
+
+impl Visitor<()> for UsageCollector {
+    fn visit_expr<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<()> {
+        let context = self.contexts.remove(&expr.id()).unwrap_or(UsageContext::Value);
+        match expr {
+            ExprKind::Path(_) => {
+                if self.is_var_place(expr) {
+                    let kind = self.usage_kind(cx, expr, context);
+                    self.usages.push((kind, expr.id()));
+                }
+            },
+            ExprKind::Field(field) => self.set_place_context(cx, expr, field.operand(), context),
+            ExprKind::Index(index) => self.set_place_context(cx, expr, index.operand(), context),
+            ExprKind::Ref(reference) => {
+                let kind = match reference.mutability() {
+                    Mutability::Mut => UsageKind::MutBorrow,
+                    _ => UsageKind::Read,
+                };
+                self.set_context(reference.expr(), UsageContext::Kind(kind));
+            },
+            ExprKind::UnaryOp(op) if matches!(op.kind(), UnaryOpKind::Deref) => {
+                self.set_context(op.expr(), UsageContext::Kind(UsageKind::Read));
+            },
+            ExprKind::BinaryOp(op)
+                if matches!(
+                    op.kind(),
+                    BinaryOpKind::Eq
+                        | BinaryOpKind::NotEq
+                        | BinaryOpKind::Greater
+                        | BinaryOpKind::GreaterEq
+                        | BinaryOpKind::Lesser
+                        | BinaryOpKind::LesserEq
+                ) =>
+            {
+                // Comparison operators take their operands by reference
+                self.set_context(op.left(), UsageContext::Kind(UsageKind::Read));
+                self.set_context(op.right(), UsageContext::Kind(UsageKind::Read));
+            },
+            ExprKind::Method(call) => {
+                let context = self.receiver_context(cx, call);
+                self.set_context(call.receiver(), context);
+            },
+            ExprKind::Match(e) => self.set_context(e.scrutinee(), UsageContext::Kind(UsageKind::Read)),
+            ExprKind::Let(e) => self.set_context(e.scrutinee(), UsageContext::Kind(UsageKind::Read)),
+            _ => {},
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    fn visit_pat<'ast>(&mut self, _cx: &'ast AstContext<'ast>, pat: PatKind<'ast>) -> ControlFlow<()> {
+        // Place expressions are only used as assignees
+        if let PatKind::Place(expr, _) = pat {
+            self.set_context(expr, UsageContext::Kind(UsageKind::Write));
+        }
+
+        ControlFlow::Continue(())
+    }
+}
@@ -3,8 +3,15 @@ use std::ops::ControlFlow;
 use marker_api::{
     ast::{
         expr::ExprKind,
+        generic::{
+            SynGenericArgKind, SynGenericArgs, SynGenericParamKind, SynGenericParams, SynTyParamBound,
+            SynWhereClauseKind,
+        },
         item::{Body, EnumVariant, Field, ItemKind},
+        pat::PatKind,
         stmt::StmtKind,
+        ty::SynTyKind,
+        AstQPath, CallableData, TraitRef,
     },
     context::AstContext,
 };
@@ -14,6 +21,10 @@ pub trait Visitor<B> {
         ControlFlow::Continue(())
     }
 
+    fn visit_item_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _item: ItemKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
     fn visit_field<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _field: &'ast Field<'ast>) -> ControlFlow<B> {
         ControlFlow::Continue(())
     }
@@ -30,13 +41,33 @@ pub trait Visitor<B> {
         ControlFlow::Continue(())
     }
 
+    fn visit_body_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _body: &'ast Body<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
     fn visit_stmt<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _stmt: StmtKind<'ast>) -> ControlFlow<B> {
         ControlFlow::Continue(())
     }
 
+    fn visit_stmt_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _stmt: StmtKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
     fn visit_expr<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _expr: ExprKind<'ast>) -> ControlFlow<B> {
         ControlFlow::Continue(())
     }
+
+    fn visit_expr_post<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _expr: ExprKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_pat<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _pat: PatKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ty<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _ty: SynTyKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
 }
 
 pub fn traverse_item<'ast, B>(
@@ -52,41 +83,77 @@ pub fn traverse_item<'ast, B>(
             }
         },
         ItemKind::Static(item) => {
+            traverse_ty(cx, visitor, item.ty())?;
             if let Some(body_id) = item.body_id() {
                 traverse_body(cx, visitor, cx.body(body_id))?;
             }
         },
         ItemKind::Const(item) => {
+            traverse_ty(cx, visitor, item.ty())?;
             if let Some(body_id) = item.body_id() {
                 traverse_body(cx, visitor, cx.body(body_id))?;
             }
         },
         ItemKind::Fn(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            for param in item.params() {
+                traverse_pat(cx, visitor, param.pat())?;
+                traverse_ty(cx, visitor, param.ty())?;
+            }
+            if let Some(ret_ty) = item.return_ty() {
+                traverse_ty(cx, visitor, *ret_ty)?;
+            }
             if let Some(body_id) = item.body_id() {
                 traverse_body(cx, visitor, cx.body(body_id))?;
             }
         },
         ItemKind::Struct(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for field in item.fields() {
                 visitor.visit_field(cx, field)?;
+                traverse_ty(cx, visitor, field.ty())?;
             }
         },
         ItemKind::Union(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for field in item.fields() {
                 visitor.visit_field(cx, field)?;
+                traverse_ty(cx, visitor, field.ty())?;
             }
         },
         ItemKind::Enum(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for variant in item.variants() {
                 visitor.visit_variant(cx, variant)?;
+                for field in variant.fields() {
+                    traverse_ty(cx, visitor, field.ty())?;
+                }
+            }
+        },
+        ItemKind::TyAlias(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            for bound in item.bounds() {
+                traverse_ty_param_bound(cx, visitor, bound)?;
+            }
+            if let Some(ty) = item.aliased_ty() {
+                traverse_ty(cx, visitor, ty)?;
             }
         },
         ItemKind::Trait(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            for bound in item.supertraits() {
+                traverse_ty_param_bound(cx, visitor, bound)?;
+            }
             for assoc_item in item.items() {
                 traverse_item(cx, visitor, assoc_item.as_item())?;
             }
         },
         ItemKind::Impl(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            if let Some(trait_ref) = item.trait_ref() {
+                traverse_trait_ref(cx, visitor, trait_ref)?;
+            }
+            traverse_ty(cx, visitor, item.ty())?;
             for assoc_item in item.items() {
                 traverse_item(cx, visitor, assoc_item.as_item())?;
             }
@@ -96,11 +163,14 @@ pub fn traverse_item<'ast, B>(
                 traverse_item(cx, visitor, ext_item.as_item())?;
             }
         },
-        ItemKind::ExternCrate(_) | ItemKind::Use(_) | ItemKind::Unstable(_) | ItemKind::TyAlias(_) => {
+        ItemKind::ExternCrate(_) | ItemKind::Use(_) | ItemKind::Unstable(_) => {
             // These items have no sub nodes, which are visited by this visitor
         },
         _ => unreachable!("all items are covered"),
     }
+
+    visitor.visit_item_post(cx, kind)?;
+
     ControlFlow::Continue(())
 }
 
@@ -113,6 +183,8 @@ pub fn traverse_body<'ast, B>(
 
     traverse_expr(cx, visitor, body.expr())?;
 
+    visitor.visit_body_post(cx, body)?;
+
     ControlFlow::Continue(())
 }
 
@@ -128,6 +200,10 @@ pub fn traverse_stmt<'ast, B>(
             traverse_item(cx, visitor, *item)?;
         },
         StmtKind::Let(lt) => {
+            traverse_pat(cx, visitor, lt.pat())?;
+            if let Some(ty) = lt.ty() {
+                traverse_ty(cx, visitor, ty)?;
+            }
             if let Some(init) = lt.init() {
                 traverse_expr(cx, visitor, init)?;
             }
@@ -141,6 +217,8 @@ pub fn traverse_stmt<'ast, B>(
         _ => unreachable!("all statements are covered"),
     }
 
+    visitor.visit_stmt_post(cx, stmt)?;
+
     ControlFlow::Continue(())
 }
 
@@ -162,6 +240,15 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::Closure(e) => {
+            for param in e.params() {
+                traverse_pat(cx, visitor, param.pat())?;
+                if let Some(ty) = param.ty() {
+                    traverse_ty(cx, visitor, ty)?;
+                }
+            }
+            if let Some(ret_ty) = e.return_ty() {
+                traverse_ty(cx, visitor, ret_ty)?;
+            }
             traverse_body(cx, visitor, cx.body(e.body_id()))?;
         },
         ExprKind::UnaryOp(e) => {
@@ -178,10 +265,12 @@ pub fn traverse_expr<'ast, B>(
             traverse_expr(cx, visitor, e.expr())?;
         },
         ExprKind::Assign(e) => {
+            traverse_pat(cx, visitor, e.assignee())?;
             traverse_expr(cx, visitor, e.value())?;
         },
         ExprKind::As(e) => {
             traverse_expr(cx, visitor, e.expr())?;
+            traverse_ty(cx, visitor, e.cast_ty())?;
         },
         ExprKind::Call(e) => {
             traverse_expr(cx, visitor, e.operand())?;
@@ -191,6 +280,7 @@ pub fn traverse_expr<'ast, B>(
         },
         ExprKind::Method(e) => {
             traverse_expr(cx, visitor, e.receiver())?;
+            traverse_generic_args(cx, visitor, e.method().generics())?;
             for arg in e.args() {
                 traverse_expr(cx, visitor, *arg)?;
             }
@@ -209,6 +299,7 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::Ctor(e) => {
+            traverse_qpath(cx, visitor, e.path())?;
             for field in e.fields() {
                 traverse_expr(cx, visitor, field.expr())?;
             }
@@ -243,11 +334,13 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::Let(e) => {
+            traverse_pat(cx, visitor, e.pat())?;
             traverse_expr(cx, visitor, e.scrutinee())?;
         },
         ExprKind::Match(e) => {
             traverse_expr(cx, visitor, e.scrutinee())?;
             for arm in e.arms() {
+                traverse_pat(cx, visitor, arm.pat())?;
                 if let Some(guard) = arm.guard() {
                     traverse_expr(cx, visitor, guard)?;
                 }
@@ -265,6 +358,7 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::For(e) => {
+            traverse_pat(cx, visitor, e.pat())?;
             traverse_expr(cx, visitor, e.iterable())?;
             traverse_expr(cx, visitor, e.block())?;
         },
@@ -278,18 +372,244 @@ pub fn traverse_expr<'ast, B>(
         ExprKind::Await(e) => {
             traverse_expr(cx, visitor, e.expr())?;
         },
+        ExprKind::Path(e) => {
+            traverse_qpath(cx, visitor, e.path())?;
+        },
         ExprKind::IntLit(_)
         | ExprKind::FloatLit(_)
         | ExprKind::StrLit(_)
         | ExprKind::CharLit(_)
         | ExprKind::BoolLit(_)
         | ExprKind::Unstable(_)
-        | ExprKind::Path(_)
         | ExprKind::Continue(_) => {
             // These expressions have no sub nodes, which are visited by this visitor
         },
         _ => unreachable!("all expressions are covered"),
     }
 
+    visitor.visit_expr_post(cx, expr)?;
+
+    ControlFlow::Continue(())
+}
+
+pub fn traverse_pat<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    pat: PatKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_pat(cx, pat)?;
+
+    match pat {
+        PatKind::Ident(p) => {
+            if let Some(binding_pat) = p.binding_pat() {
+                traverse_pat(cx, visitor, binding_pat)?;
+            }
+        },
+        PatKind::Ref(p) => {
+            traverse_pat(cx, visitor, p.pattern())?;
+        },
+        PatKind::Struct(p) => {
+            traverse_qpath(cx, visitor, p.path())?;
+            for field in p.fields() {
+                traverse_pat(cx, visitor, field.pat())?;
+            }
+        },
+        PatKind::Tuple(p) => {
+            for el in p.elements() {
+                traverse_pat(cx, visitor, *el)?;
+            }
+        },
+        PatKind::Slice(p) => {
+            for el in p.elements() {
+                traverse_pat(cx, visitor, *el)?;
+            }
+        },
+        PatKind::Or(p) => {
+            for pat in p.patterns() {
+                traverse_pat(cx, visitor, *pat)?;
+            }
+        },
+        PatKind::Place(expr, _) => {
+            traverse_expr(cx, visitor, expr)?;
+        },
+        PatKind::Lit(lit, _) => {
+            traverse_expr(cx, visitor, lit.into())?;
+        },
+        PatKind::Path(p) => {
+            traverse_qpath(cx, visitor, p.path())?;
+        },
+        PatKind::Range(p) => {
+            if let Some(start) = p.start() {
+                traverse_expr(cx, visitor, start)?;
+            }
+            if let Some(end) = p.end() {
+                traverse_expr(cx, visitor, end)?;
+            }
+        },
+        PatKind::Wildcard(_) | PatKind::Rest(_) | PatKind::Unstable(_) => {
+            // These patterns have no sub nodes, which are visited by this visitor
+        },
+        _ => unreachable!("all patterns are covered"),
+    }
+
+    ControlFlow::Continue(())
+}
+
+pub fn traverse_ty<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    ty: SynTyKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_ty(cx, ty)?;
+
+    match ty {
+        SynTyKind::Tuple(t) => {
+            for el in t.types() {
+                traverse_ty(cx, visitor, *el)?;
+            }
+        },
+        SynTyKind::Array(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+            if let Some(len) = t.len() {
+                traverse_expr(cx, visitor, len.expr())?;
+            }
+        },
+        SynTyKind::Slice(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        SynTyKind::Closure(t) => {
+            traverse_callable_tys(cx, visitor, t)?;
+        },
+        SynTyKind::FnPtr(t) => {
+            traverse_callable_tys(cx, visitor, t)?;
+        },
+        SynTyKind::Ref(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        SynTyKind::RawPtr(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        SynTyKind::TraitObj(t) => {
+            for bound in t.trait_bounds() {
+                traverse_ty_param_bound(cx, visitor, bound)?;
+            }
+        },
+        SynTyKind::ImplTrait(t) => {
+            for bound in t.trait_bounds() {
+                traverse_ty_param_bound(cx, visitor, bound)?;
+            }
+        },
+        SynTyKind::Path(t) => {
+            traverse_qpath(cx, visitor, t.path())?;
+        },
+        SynTyKind::Bool(_) | SynTyKind::Num(_) | SynTyKind::Text(_) | SynTyKind::Never(_) | SynTyKind::Inferred(_) => {
+            // These types have no sub nodes, which are visited by this visitor
+        },
+        _ => unreachable!("all types are covered"),
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_callable_tys<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    callable: &dyn CallableData<'ast>,
+) -> ControlFlow<B> {
+    for param in callable.params() {
+        if let Some(ty) = param.ty() {
+            traverse_ty(cx, visitor, ty)?;
+        }
+    }
+    if let Some(ret_ty) = callable.return_ty() {
+        traverse_ty(cx, visitor, *ret_ty)?;
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_qpath<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    path: &AstQPath<'ast>,
+) -> ControlFlow<B> {
+    if let Some(self_ty) = path.self_ty() {
+        traverse_ty(cx, visitor, self_ty)?;
+    }
+    if let Some(path_ty) = path.path_ty() {
+        // The segments of the path type are also included in the segments of
+        // this path. Only the generics of the last segment belong to this path.
+        traverse_ty(cx, visitor, path_ty)?;
+        traverse_generic_args(cx, visitor, path.generics())?;
+    } else {
+        for seg in path.segments() {
+            traverse_generic_args(cx, visitor, seg.generics())?;
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_trait_ref<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    trait_ref: &TraitRef<'ast>,
+) -> ControlFlow<B> {
+    traverse_generic_args(cx, visitor, trait_ref.generics())
+}
+
+fn traverse_ty_param_bound<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    bound: &SynTyParamBound<'ast>,
+) -> ControlFlow<B> {
+    match bound {
+        SynTyParamBound::TraitBound(bound) => traverse_trait_ref(cx, visitor, bound.trait_ref()),
+        _ => ControlFlow::Continue(()),
+    }
+}
+
+fn traverse_generic_args<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    args: &SynGenericArgs<'ast>,
+) -> ControlFlow<B> {
+    for arg in args.args() {
+        match arg {
+            SynGenericArgKind::Ty(arg) => traverse_ty(cx, visitor, arg.ty())?,
+            SynGenericArgKind::Binding(arg) => traverse_ty(cx, visitor, arg.ty())?,
+            SynGenericArgKind::Const(arg) => traverse_expr(cx, visitor, arg.expr().expr())?,
+            _ => {},
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_generic_params<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    generics: &SynGenericParams<'ast>,
+) -> ControlFlow<B> {
+    for param in generics.params() {
+        if let SynGenericParamKind::Const(param) = param {
+            traverse_ty(cx, visitor, param.ty())?;
+            if let Some(default) = param.default() {
+                traverse_expr(cx, visitor, default.expr())?;
+            }
+        }
+    }
+    for clause in generics.clauses() {
+        if let SynWhereClauseKind::Ty(clause) = clause {
+            if let Some(params) = clause.params() {
+                traverse_generic_params(cx, visitor, params)?;
+            }
+            traverse_ty(cx, visitor, clause.ty())?;
+            for bound in clause.bounds() {
+                traverse_ty_param_bound(cx, visitor, bound)?;
+            }
+        }
+    }
+
     ControlFlow::Continue(())
 }